@@ -1,7 +1,11 @@
-pub const RESEARCH_REWARD_PERCENTAGES: [u8; 3] = [8, 3, 1];
+pub const MAX_RESEARCH_REWARD_LEVELS: usize = 8;
+pub const MAX_GOVERNANCE_DELAY: i64 = 30 * 24 * 60 * 60;
 pub const PCRAZY_INJECTION_REWARDS: u64 = 50;
 pub const PCRAZY_MUTATION_REWARDS: u64 = 10;
 pub const PCRAZY_DAILY_STREAK_BASE_REWARDS: u64 = 25;
 pub const PCRAZY_RECRUITMENT_REWARDS: u64 = 100;
 pub const SECONDS_IN_24H: u64 = 24 * 60 * 60;
 pub const VAPORIZATION_PERCENTAGE: u8 = 25;
+pub const YIELD_INDEX_PRECISION: u128 = 1_000_000_000_000_000_000_000_000;
+pub const MAX_CURVE_BREAKPOINTS: usize = 8;
+pub const MAX_CRAZY_DECIMALS: u8 = 9;
@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 pub enum CrazySolError {
     #[msg("Laboratory already initialized")]
     AlreadyInitialized,
-    #[msg("Injection amount too small, minimum is 0.05 SOL")]
+    #[msg("Injection amount below the laboratory minimum")]
     InjectionTooSmall,
     #[msg("Cooldown period is still active")]
     CooldownActive,
@@ -33,5 +33,103 @@ pub enum CrazySolError {
     #[msg("Unauthorized action")]
     Unauthorized,
     #[msg("Username too long")]
-    UsernameTooLong
+    UsernameTooLong,
+    #[msg("Not enough Pills")]
+    InsufficientPills,
+    #[msg("Output below the accepted minimum")]
+    SlippageExceeded,
+    #[msg("Not enough SOL in the Reactor")]
+    InsufficientReserves,
+    #[msg("Average price per Pill above the accepted maximum")]
+    PricePerPillTooHigh,
+    #[msg("Invalid bonding curve")]
+    InvalidBondingCurve,
+    #[msg("Invalid research reward schedule")]
+    InvalidResearchRewardSchedule,
+    #[msg("Invalid handle")]
+    InvalidHandle,
+    #[msg("Invalid referral code")]
+    InvalidReferralCode,
+    #[msg("Invalid director")]
+    InvalidDirector,
+    #[msg("No pending director")]
+    NoPendingDirector,
+    #[msg("Pending change is still timelocked")]
+    ChangeNotReady,
+    #[msg("Invalid governance delay")]
+    InvalidGovernanceDelay,
+    #[msg("Invalid roles")]
+    InvalidRoles,
+    #[msg("Invalid council configuration")]
+    InvalidCouncil,
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+    #[msg("Invalid proposal")]
+    InvalidProposal,
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal was created for a previous council")]
+    StaleProposal,
+    #[msg("Not enough approvals")]
+    ThresholdNotMet,
+    #[msg("Invalid formula bounds")]
+    InvalidFormulaBounds,
+    #[msg("Initial Pill potency outside the allowed range")]
+    InitialPotencyOutOfBounds,
+    #[msg("First injection bonus outside the allowed range")]
+    FirstInjectionBonusOutOfBounds,
+    #[msg("First mutation bonus outside the allowed range")]
+    FirstMutationBonusOutOfBounds,
+    #[msg("Pill potency change exceeds the allowed maximum")]
+    PotencyChangeTooLarge,
+    #[msg("Invalid season schedule")]
+    InvalidSeasonSchedule,
+    #[msg("Season has not started yet")]
+    SeasonNotStarted,
+    #[msg("Injection period has ended")]
+    InjectionPeriodEnded,
+    #[msg("Season has been finalized")]
+    SeasonFinalized,
+    #[msg("Settlement time not reached")]
+    SettlementNotReached,
+    #[msg("Season has not been finalized")]
+    SeasonNotFinalized,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("Emergency lockdown must be active")]
+    EmergencyLockdownRequired,
+    #[msg("Emergency exit is already active")]
+    EmergencyExitActive,
+    #[msg("Emergency exit is not active")]
+    EmergencyExitNotActive,
+    #[msg("CRAZY mint must be empty and owned by the Reactor")]
+    InvalidCrazyMint,
+    #[msg("Innoculation has not happened yet")]
+    InnoculationNotHappened,
+    #[msg("CRAZY already claimed")]
+    CrazyAlreadyClaimed,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Nothing vested to claim yet")]
+    NothingVested,
+    #[msg("pCRAZY transfers are disabled")]
+    PcrazyTransfersDisabled,
+    #[msg("pCRAZY transfer exceeds the allowed maximum")]
+    PcrazyTransferTooLarge,
+    #[msg("Invalid funding campaign")]
+    InvalidFundingCampaign,
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Funding already claimed")]
+    FundingAlreadyClaimed,
+    #[msg("Funding campaign budget exhausted")]
+    FundingCampaignExhausted,
+    #[msg("Funding batch has no entries")]
+    EmptyFundingBatch,
+    #[msg("Dissolve payout exceeds the lamports put on the curve")]
+    DissolveExceedsCostBasis,
+    #[msg("Minimum injection must be greater than zero")]
+    InvalidMinInjection,
+    #[msg("First-action bonus change exceeds the allowed maximum")]
+    BonusChangeTooLarge
 }
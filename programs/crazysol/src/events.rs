@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{ ReactionFormula};
+use crate::{FormulaBounds, ParameterChange, PcrazyTransferPolicy, ReactionFormula, SeasonSchedule};
 
 #[event]
 pub struct InjectEvent {
@@ -15,6 +15,7 @@ pub struct InjectEvent {
 #[event]
 pub struct InnoculateEvent {
     pub innoculation_happened: bool,
+    pub crazy_mint: Pubkey,
     pub timestamp: i64,
 }
 
@@ -27,6 +28,16 @@ pub struct DistillEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct DissolveEvent {
+    pub scientist: Pubkey,
+    pub dissolved_pill: u128,
+    pub lamports_out: u64,
+    pub fee: u64,
+    pub total_owned_pill: u128,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MutateEvent {
     pub scientist: Pubkey,
@@ -54,12 +65,27 @@ pub struct GiveSomePcrazyEvent {
     pub new_available_pcrazy_amount: u64,
 }
 
+#[event]
+pub struct GivePublicFundingBatchEvent {
+    pub recipients: u32,
+    pub total_amount: u64,
+    pub new_available_pcrazy_amount: u64,
+}
+
 #[event]
 pub struct DepositFromExternalReactorEvent {
     pub amount: u64,
     pub timestamp: i64
 }
 
+#[event]
+pub struct ReconcileReservesEvent {
+    pub previous_sol_reserves: u64,
+    pub reconciled_sol_reserves: u64,
+    pub discrepancy: i128,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct IncreasepCRAZYLiquidityEvent {
     pub previous_available_pcrazy: u64,
@@ -68,6 +94,7 @@ pub struct IncreasepCRAZYLiquidityEvent {
 
 #[event]
 pub struct InitializeEvent {
+    pub laboratory_id: u64,
     pub director: Pubkey,
     pub big_pharma: Pubkey,
     pub containment_tax_bps: u16,
@@ -76,6 +103,13 @@ pub struct InitializeEvent {
     pub reaction_formula: ReactionFormula,
 }
 
+#[event]
+pub struct ClaimReferralCodeEvent {
+    pub scientist: Pubkey,
+    pub code: String,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RegisterScientistEvent {
     pub scientist: Pubkey,
@@ -83,17 +117,121 @@ pub struct RegisterScientistEvent {
     pub recruiter_reward: Option<u64>,
 }
 
+#[event]
+pub struct RenameScientistEvent {
+    pub scientist: Pubkey,
+    pub old_username: String,
+    pub new_username: String,
+}
+
 #[event]
 pub struct SwitchEmergencyLockdownEvent {
     pub is_emergency_lockdown: bool,
 }
 
 #[event]
-pub struct ReplaceDirectorEvent {
+pub struct ClaimCrazyEvent {
+    pub scientist: Pubkey,
+    pub pcrazy_amount: u64,
+    pub crazy_amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EnableEmergencyExitEvent {
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawEvent {
+    pub scientist: Pubkey,
+    pub yield_amount: u64,
+    pub withdrawn_pill: u128,
+    pub pill_payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SetPauseFlagsEvent {
+    pub previous_pause_flags: u8,
+    pub new_pause_flags: u8,
+}
+
+#[event]
+pub struct CreateCouncilEvent {
+    pub council: Pubkey,
+    pub council_authority: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct UpdateCouncilEvent {
+    pub council: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub version: u32,
+}
+
+#[event]
+pub struct CreateProposalEvent {
+    pub council: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ApproveProposalEvent {
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub approvals: u32,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ExecuteProposalEvent {
+    pub council: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct GrantRoleEvent {
+    pub grantee: Pubkey,
+    pub granted_roles: u8,
+    pub roles: u8,
+    pub granted_by: Pubkey,
+}
+
+#[event]
+pub struct RevokeRoleEvent {
+    pub grantee: Pubkey,
+    pub revoked_roles: u8,
+    pub roles: u8,
+    pub revoked_by: Pubkey,
+}
+
+#[event]
+pub struct ProposeDirectorEvent {
+    pub director: Pubkey,
+    pub pending_director: Pubkey,
+}
+
+#[event]
+pub struct AcceptDirectorEvent {
     pub old_director: Pubkey,
     pub new_director: Pubkey,
 }
 
+#[event]
+pub struct CancelDirectorProposalEvent {
+    pub director: Pubkey,
+    pub cancelled_director: Pubkey,
+}
+
 #[event]
 pub struct UpdateBigPharmaEvent {
     pub old_big_pharma: Pubkey,
@@ -116,3 +254,97 @@ pub struct UpdateRewardRatePerPillEvent {
 pub struct UpdateReactionFormulaEvent {
     pub new_reaction_formula: ReactionFormula,
 }
+
+#[event]
+pub struct UpdateFormulaBoundsEvent {
+    pub previous_formula_bounds: FormulaBounds,
+    pub new_formula_bounds: FormulaBounds,
+}
+
+#[event]
+pub struct UpdateGovernanceDelayEvent {
+    pub previous_governance_delay: i64,
+    pub new_governance_delay: i64,
+}
+
+#[event]
+pub struct QueueParameterChangeEvent {
+    pub change: ParameterChange,
+    pub proposer: Pubkey,
+    pub queued_at: i64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct CancelParameterChangeEvent {
+    pub change: ParameterChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateResearchRewardScheduleEvent {
+    pub previous_research_reward_schedule: Vec<u16>,
+    pub new_research_reward_schedule: Vec<u16>,
+}
+
+#[event]
+pub struct UpdateSeasonScheduleEvent {
+    pub previous_season_schedule: SeasonSchedule,
+    pub new_season_schedule: SeasonSchedule,
+}
+
+#[event]
+pub struct FinalizeSeasonEvent {
+    pub final_pill_supply: u128,
+    pub settlement_reserves: u64,
+    pub outstanding_yield: u64,
+    pub final_lamports_per_pill: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimSettlementEvent {
+    pub scientist: Pubkey,
+    pub yield_amount: u64,
+    pub settled_pill: u128,
+    pub pill_payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdatePcrazyTransferPolicyEvent {
+    pub previous_pcrazy_transfer_policy: PcrazyTransferPolicy,
+    pub new_pcrazy_transfer_policy: PcrazyTransferPolicy,
+}
+
+#[event]
+pub struct TransferPcrazyEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreateFundingCampaignEvent {
+    pub funding_campaign: Pubkey,
+    pub campaign_id: u64,
+    pub merkle_root: [u8; 32],
+    pub budget: u64,
+    pub max_claims: u32,
+}
+
+#[event]
+pub struct ClaimPublicFundingEvent {
+    pub funding_campaign: Pubkey,
+    pub scientist: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CloseFundingCampaignEvent {
+    pub funding_campaign: Pubkey,
+    pub returned_amount: u64,
+}
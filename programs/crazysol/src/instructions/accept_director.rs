@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::AcceptDirectorEvent, require_initialized, states::LaboratoryState};
+
+#[derive(Accounts)]
+pub struct AcceptDirector<'info> {
+    #[account(
+        mut,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub new_director: Signer<'info>,
+}
+
+pub fn handle_accept_director(
+    ctx: Context<AcceptDirector>
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let new_director = ctx.accounts.new_director.key();
+
+    require_initialized(laboratory_state)?;
+
+    match laboratory_state.pending_director {
+        Some(pending_director) => require!(
+            pending_director == new_director,
+            CrazySolError::Unauthorized
+        ),
+        None => return Err(CrazySolError::NoPendingDirector.into()),
+    }
+
+    let old_director = laboratory_state.director;
+
+    laboratory_state.director = new_director;
+    laboratory_state.pending_director = None;
+
+    emit!(AcceptDirectorEvent {
+        old_director,
+        new_director,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::ApproveProposalEvent, states::{Council, Proposal}};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [Council::SEED, council.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        has_one = council,
+        seeds = [Proposal::SEED, council.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub member: Signer<'info>,
+}
+
+pub fn handle_approve_proposal(
+    ctx: Context<ApproveProposal>
+) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    let member = ctx.accounts.member.key();
+
+    proposal.approve(council, &member)?;
+
+    emit!(ApproveProposalEvent {
+        proposal: proposal.key(),
+        member,
+        approvals: proposal.approval_count(),
+        threshold: council.threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::CancelDirectorProposalEvent, require_initialized, states::LaboratoryState};
+
+#[derive(Accounts)]
+pub struct CancelDirectorProposal<'info> {
+    #[account(
+        mut,
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+}
+
+pub fn handle_cancel_director_proposal(
+    ctx: Context<CancelDirectorProposal>
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+
+    let cancelled_director = laboratory_state.pending_director
+        .take()
+        .ok_or(CrazySolError::NoPendingDirector)?;
+
+    emit!(CancelDirectorProposalEvent {
+        director: laboratory_state.director,
+        cancelled_director,
+    });
+
+    Ok(())
+}
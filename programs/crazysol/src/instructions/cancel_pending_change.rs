@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CrazySolError,
+    events::CancelParameterChangeEvent,
+    require_initialized, require_role,
+    states::{LaboratoryState, ParameterChange, ParameterChangeKind, PendingChange, RoleGrant},
+};
+
+#[derive(Accounts)]
+#[instruction(kind: ParameterChangeKind)]
+pub struct CancelPendingChange<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [PendingChange::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), &[kind as u8]],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    /// CHECK: Receives the rent of the cancelled change, validated against pending_change
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_cancel_pending_change(
+    ctx: Context<CancelPendingChange>,
+    _kind: ParameterChangeKind
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+
+    // Each change can be cancelled by whoever is allowed to queue it.
+    let role = match ctx.accounts.pending_change.change {
+        ParameterChange::ReactionFormula(_) | ParameterChange::ContainmentTaxBps(_) => RoleGrant::FORMULA_MANAGER,
+        ParameterChange::BigPharma(_) => RoleGrant::TREASURER,
+        ParameterChange::GovernanceDelay(_) => {
+            require!(
+                ctx.accounts.authority.key() == laboratory_state.director,
+                CrazySolError::Unauthorized
+            );
+            0
+        }
+    };
+
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        role
+    )?;
+
+    emit!(CancelParameterChangeEvent {
+        change: ctx.accounts.pending_change.change,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, MintTo, Token, TokenAccount},
+};
+
+use crate::{errors::CrazySolError, events::ClaimCrazyEvent, require_initialized, states::{LaboratoryState, ReactorState, ScientistState, VestingState}};
+
+#[derive(Accounts)]
+pub struct ClaimCrazy<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = VestingState::SIZE,
+        seeds = [VestingState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub vesting_state: Account<'info, VestingState>,
+
+    #[account(
+        mut,
+        address = laboratory_state.crazy_mint @ CrazySolError::InvalidCrazyMint,
+    )]
+    pub crazy_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = crazy_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_crazy(
+    ctx: Context<ClaimCrazy>,
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let vesting_state = &mut ctx.accounts.vesting_state;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require!(
+        laboratory_state.innoculation_happened,
+        CrazySolError::InnoculationNotHappened
+    );
+    require!(
+        !laboratory_state.emergency_lockdown,
+        CrazySolError::CurrentlyPaused
+    );
+
+    // The first claim moves the pCRAZY balance, frozen since innoculation, into the vesting account.
+    if vesting_state.owner == Pubkey::default() {
+        require!(
+            scientist_state.owned_pcrazy > 0,
+            CrazySolError::InsufficientpCRAZY
+        );
+
+        vesting_state.owner = ctx.accounts.owner.key();
+        vesting_state.total_amount = scientist_state.owned_pcrazy;
+        vesting_state.claimed_amount = 0;
+        scientist_state.owned_pcrazy = 0;
+    }
+
+    require!(
+        vesting_state.claimed_amount < vesting_state.total_amount,
+        CrazySolError::CrazyAlreadyClaimed
+    );
+
+    let vested_amount = laboratory_state.vesting_schedule.vested_amount(
+        vesting_state.total_amount,
+        laboratory_state.innoculated_at,
+        timestamp
+    )?;
+    let pcrazy_amount = vested_amount
+        .checked_sub(vesting_state.claimed_amount)
+        .ok_or(CrazySolError::Overflow)?;
+    require!(
+        pcrazy_amount > 0,
+        CrazySolError::NothingVested
+    );
+
+    // pCRAZY is counted in whole tokens, the mint in base units.
+    let crazy_amount = 10u64
+        .checked_pow(ctx.accounts.crazy_mint.decimals as u32)
+        .and_then(|unit| pcrazy_amount.checked_mul(unit))
+        .ok_or(CrazySolError::Overflow)?;
+
+    vesting_state.claimed_amount = vested_amount;
+
+    let laboratory_id_bytes = laboratory_state.laboratory_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        ReactorState::SEED,
+        laboratory_id_bytes.as_ref(),
+        &[ctx.bumps.reactor_state],
+    ]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.crazy_mint.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.reactor_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        crazy_amount,
+    )?;
+
+    emit!(ClaimCrazyEvent {
+        scientist: ctx.accounts.owner.key(),
+        pcrazy_amount,
+        crazy_amount,
+        claimed_amount: vesting_state.claimed_amount,
+        total_amount: vesting_state.total_amount,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::ClaimPublicFundingEvent, funding_leaf, require_initialized, require_innoculation_not_happened, require_operational, states::{FundingCampaign, LaboratoryState, ScientistState}, verify_merkle_proof};
+
+#[derive(Accounts)]
+pub struct ClaimPublicFunding<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [FundingCampaign::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), funding_campaign.campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub funding_campaign: Account<'info, FundingCampaign>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), scientist_state.owner.as_ref()],
+        bump
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+}
+
+pub fn handle_claim_public_funding(
+    ctx: Context<ClaimPublicFunding>,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let funding_campaign = &mut ctx.accounts.funding_campaign;
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_operational(laboratory_state)?;
+    require_innoculation_not_happened(laboratory_state)?;
+
+    let leaf = funding_leaf(
+        laboratory_state.laboratory_id,
+        funding_campaign.campaign_id,
+        index,
+        &scientist_state.owner,
+        amount
+    );
+    require!(
+        verify_merkle_proof(&proof, &funding_campaign.merkle_root, leaf),
+        CrazySolError::InvalidMerkleProof
+    );
+
+    funding_campaign.record_claim(index, amount)?;
+
+    scientist_state.owned_pcrazy = scientist_state.owned_pcrazy
+        .checked_add(amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(ClaimPublicFundingEvent {
+        funding_campaign: funding_campaign.key(),
+        scientist: scientist_state.owner,
+        index,
+        amount,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::ClaimReferralCodeEvent, normalize_handle, require_initialized, require_operational, states::{LaboratoryState, ReferralCode, ScientistState}};
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct ClaimReferralCode<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ReferralCode::SIZE,
+        seeds = [ReferralCode::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), code.as_bytes()],
+        bump
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_referral_code(
+    ctx: Context<ClaimReferralCode>,
+    code: String
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let referral_code = &mut ctx.accounts.referral_code;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_operational(laboratory_state)?;
+
+    // The PDA is derived from the raw argument, so only canonical codes are accepted.
+    let normalized_code = normalize_handle(
+        &code,
+        ReferralCode::MIN_CODE_LENGTH,
+        ReferralCode::MAX_CODE_LENGTH
+    )?;
+    require!(
+        normalized_code == code,
+        CrazySolError::InvalidHandle
+    );
+
+    referral_code.code = normalized_code;
+    referral_code.owner = ctx.accounts.owner.key();
+    referral_code.created_at = timestamp;
+
+    emit!(ClaimReferralCodeEvent {
+        scientist: referral_code.owner,
+        code: referral_code.code.clone(),
+        timestamp
+    });
+
+    Ok(())
+}
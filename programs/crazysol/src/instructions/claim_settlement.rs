@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::ClaimSettlementEvent, release_reactor_lamports, require_initialized, require_not_paused, states::{ExperimentState, LaboratoryState, ReactorState, ScientistState}};
+
+#[derive(Accounts)]
+pub struct ClaimSettlement<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        mut,
+        seeds = [ExperimentState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub experiment_state: Account<'info, ExperimentState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_settlement(
+    ctx: Context<ClaimSettlement>,
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let experiment_state = &mut ctx.accounts.experiment_state;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_WITHDRAWALS)?;
+    require!(
+        laboratory_state.season_finalized,
+        CrazySolError::SeasonNotFinalized
+    );
+
+    scientist_state.incubate_serum(reactor_state, current_ts)?;
+
+    let yield_amount = scientist_state.distillable_yield;
+    let settled_pill = scientist_state.owned_pill;
+    let pill_payout = reactor_state.settlement_payout(settled_pill)?;
+
+    let total_payout = yield_amount
+        .checked_add(pill_payout)
+        .ok_or(CrazySolError::Overflow)?;
+    if total_payout == 0 {
+        return Err(CrazySolError::NoYield.into());
+    }
+
+    release_reactor_lamports(
+        reactor_state,
+        &ctx.accounts.owner.to_account_info(),
+        total_payout
+    )?;
+
+    reactor_state.settle_yield(yield_amount)?;
+
+    // pill_supply stays frozen at its finalized value so every claim uses the same rate.
+    scientist_state.distillable_yield = 0;
+    scientist_state.owned_pill = 0;
+    scientist_state.sync_yield_debt(reactor_state)?;
+
+    scientist_state.earned_sol = scientist_state.earned_sol
+        .checked_add(total_payout)
+        .ok_or(CrazySolError::Overflow)?;
+
+    experiment_state.total_yield_distilled = experiment_state
+        .total_yield_distilled
+        .checked_add(yield_amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(ClaimSettlementEvent {
+        scientist: ctx.accounts.owner.key(),
+        yield_amount,
+        settled_pill,
+        pill_payout,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::CloseFundingCampaignEvent, require_initialized, require_role, states::{FundingCampaign, LaboratoryState, ReactorState, RoleGrant}};
+
+#[derive(Accounts)]
+pub struct CloseFundingCampaign<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        mut,
+        has_one = created_by,
+        close = created_by,
+        seeds = [FundingCampaign::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), funding_campaign.campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub funding_campaign: Account<'info, FundingCampaign>,
+
+    /// CHECK: Receives the rent of the closed campaign, validated against funding_campaign
+    #[account(mut)]
+    pub created_by: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_funding_campaign(
+    ctx: Context<CloseFundingCampaign>,
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let funding_campaign = &ctx.accounts.funding_campaign;
+
+    require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::DISTRIBUTOR
+    )?;
+
+    let returned_amount = funding_campaign.budget
+        .checked_sub(funding_campaign.claimed_amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    reactor_state.owned_pcrazy = reactor_state.owned_pcrazy
+        .checked_add(returned_amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(CloseFundingCampaignEvent {
+        funding_campaign: funding_campaign.key(),
+        returned_amount,
+    });
+
+    Ok(())
+}
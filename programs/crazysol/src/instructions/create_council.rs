@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::CreateCouncilEvent, require_initialized, states::{Council, LaboratoryState}, validate_council_members};
+
+#[derive(Accounts)]
+pub struct CreateCouncil<'info> {
+    #[account(
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        init,
+        payer = director,
+        space = Council::SIZE,
+        seeds = [Council::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_council(
+    ctx: Context<CreateCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let council = &mut ctx.accounts.council;
+
+    require_initialized(laboratory_state)?;
+    validate_council_members(&members, threshold)?;
+
+    let (council_authority, authority_bump) = Pubkey::find_program_address(
+        &[Council::AUTHORITY_SEED, council.key().as_ref()],
+        ctx.program_id
+    );
+
+    council.laboratory_id = laboratory_state.laboratory_id;
+    council.members = members;
+    council.threshold = threshold;
+    council.version = 0;
+    council.proposal_count = 0;
+    council.authority_bump = authority_bump;
+
+    emit!(CreateCouncilEvent {
+        council: council.key(),
+        council_authority,
+        members: council.members.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::CreateFundingCampaignEvent, require_initialized, require_innoculation_not_happened, require_role, states::{FundingCampaign, LaboratoryState, ReactorState, RoleGrant}};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, merkle_root: [u8; 32], budget: u64, max_claims: u32)]
+pub struct CreateFundingCampaign<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FundingCampaign::size(max_claims),
+        seeds = [FundingCampaign::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub funding_campaign: Account<'info, FundingCampaign>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_funding_campaign(
+    ctx: Context<CreateFundingCampaign>,
+    campaign_id: u64,
+    merkle_root: [u8; 32],
+    budget: u64,
+    max_claims: u32
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let funding_campaign = &mut ctx.accounts.funding_campaign;
+
+    require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::DISTRIBUTOR
+    )?;
+    require_innoculation_not_happened(laboratory_state)?;
+
+    require!(
+        budget > 0 && max_claims > 0 && max_claims <= FundingCampaign::MAX_CLAIMS,
+        CrazySolError::InvalidFundingCampaign
+    );
+
+    // The budget is set aside up front so claims can never overdraw the pool.
+    if reactor_state.owned_pcrazy < budget {
+        return Err(CrazySolError::InsufficientpCRAZY.into());
+    }
+
+    reactor_state.owned_pcrazy = reactor_state.owned_pcrazy
+        .checked_sub(budget)
+        .ok_or(CrazySolError::Overflow)?;
+
+    funding_campaign.campaign_id = campaign_id;
+    funding_campaign.merkle_root = merkle_root;
+    funding_campaign.budget = budget;
+    funding_campaign.claimed_amount = 0;
+    funding_campaign.max_claims = max_claims;
+    funding_campaign.created_by = ctx.accounts.authority.key();
+    funding_campaign.created_at = Clock::get()?.unix_timestamp;
+    funding_campaign.claimed_bitmap = vec![0; FundingCampaign::bitmap_len(max_claims)];
+
+    emit!(CreateFundingCampaignEvent {
+        funding_campaign: funding_campaign.key(),
+        campaign_id,
+        merkle_root,
+        budget,
+        max_claims,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::CreateProposalEvent, states::{Council, Proposal, ProposalAccountMeta}};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [Council::SEED, council.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::SIZE,
+        seeds = [Proposal::SEED, council.key().as_ref(), council.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_proposal(
+    ctx: Context<CreateProposal>,
+    instruction_data: Vec<u8>,
+    accounts: Vec<ProposalAccountMeta>
+) -> Result<()> {
+    let council = &mut ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = ctx.accounts.proposer.key();
+
+    require!(
+        instruction_data.len() <= Proposal::MAX_INSTRUCTION_DATA_LENGTH
+            && accounts.len() <= Proposal::MAX_ACCOUNTS,
+        CrazySolError::InvalidProposal
+    );
+
+    proposal.council = council.key();
+    proposal.id = council.proposal_count;
+    proposal.proposer = proposer;
+    proposal.council_version = council.version;
+    proposal.instruction_data = instruction_data;
+    proposal.accounts = accounts;
+    proposal.approvals = 0;
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+
+    proposal.approve(council, &proposer)?;
+
+    council.proposal_count = council.proposal_count
+        .checked_add(1)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(CreateProposalEvent {
+        council: council.key(),
+        proposal: proposal.key(),
+        id: proposal.id,
+        proposer,
+    });
+
+    Ok(())
+}
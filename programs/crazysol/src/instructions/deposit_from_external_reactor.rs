@@ -1,11 +1,11 @@
 use anchor_lang::{prelude::*, system_program};
 
-use crate::{errors::CrazySolError, events::DepositFromExternalReactorEvent, require_initialized, states::{LaboratoryState, ReactorState}};
+use crate::{errors::CrazySolError, events::DepositFromExternalReactorEvent, require_initialized, require_season_not_finalized, states::{LaboratoryState, ReactorState}};
 
 #[derive(Accounts)]
 pub struct DepositFromExternalReactor<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
@@ -13,7 +13,7 @@ pub struct DepositFromExternalReactor<'info> {
     #[account(
         mut,
         has_one = external_reactor @ CrazySolError::Unauthorized,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub reactor_state: Account<'info, ReactorState>,
@@ -33,6 +33,7 @@ pub fn handle_deposit_from_external_reactor(
     let timestamp = Clock::get()?.unix_timestamp;
 
     require_initialized(laboratory_state)?;
+    require_season_not_finalized(laboratory_state)?;
 
     let transfer_instruction = system_program::Transfer {
         from: ctx.accounts.external_reactor.to_account_info(),
@@ -46,10 +47,9 @@ pub fn handle_deposit_from_external_reactor(
 
     system_program::transfer(cpi_ctx, lamports)?;
 
-    reactor_state.sol_reserves = reactor_state
-        .sol_reserves
-        .checked_add(lamports)
-        .ok_or(CrazySolError::Overflow)?;
+    reactor_state.record_inflow(lamports)?;
+
+    reactor_state.distribute_yield(lamports)?;
 
     emit!(DepositFromExternalReactorEvent {
         amount: lamports,
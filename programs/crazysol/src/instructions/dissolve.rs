@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+use crate::{compute_rate_of_dissolution, errors::CrazySolError, events::DissolveEvent, release_reactor_lamports, require_initialized, require_not_paused, require_season_not_finalized, states::{ExperimentState, LaboratoryState, ReactorState, ScientistState}, withdrawable_reserves};
+
+#[derive(Accounts)]
+pub struct Dissolve<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        mut,
+        seeds = [ExperimentState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub experiment_state: Account<'info, ExperimentState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: big_pharma is validated by address and stored in laboratory state
+    #[account(mut, address = laboratory_state.big_pharma)]
+    pub big_pharma: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_dissolve(
+    ctx: Context<Dissolve>,
+    pill_amount: u128,
+    min_lamports_out: u64,
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let experiment_state = &mut ctx.accounts.experiment_state;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_WITHDRAWALS)?;
+    require_season_not_finalized(laboratory_state)?;
+
+    if pill_amount == 0 || pill_amount > scientist_state.owned_pill {
+        return Err(CrazySolError::InsufficientPills.into());
+    }
+
+    require!(
+        pill_amount <= reactor_state.pill_supply,
+        CrazySolError::InsufficientPills
+    );
+
+    scientist_state.incubate_serum(reactor_state, current_ts)?;
+
+    let gross_lamports: u64 = compute_rate_of_dissolution(
+        reactor_state.pill_supply,
+        pill_amount,
+        &laboratory_state.reaction_formula.bonding_curve
+    )?
+        .try_into()
+        .map_err(|_| CrazySolError::Overflow)?;
+
+    // Lamports backing yield already distributed to Pill holders are not for sale.
+    let available_balance = withdrawable_reserves(reactor_state)?
+        .saturating_sub(reactor_state.outstanding_yield);
+
+    if gross_lamports > available_balance {
+        return Err(CrazySolError::InsufficientReserves.into());
+    }
+
+    scientist_state.redeem_curve_cost(gross_lamports)?;
+
+    let fee = gross_lamports
+        .checked_mul(laboratory_state.containment_tax_bps as u64)
+        .ok_or(CrazySolError::Overflow)?
+        .checked_div(10_000)
+        .ok_or(CrazySolError::Overflow)?;
+
+    let lamports_out = gross_lamports
+        .checked_sub(fee)
+        .ok_or(CrazySolError::Overflow)?;
+
+    if lamports_out < min_lamports_out {
+        return Err(CrazySolError::SlippageExceeded.into());
+    }
+
+    if fee > 0 {
+        release_reactor_lamports(
+            reactor_state,
+            &ctx.accounts.big_pharma.to_account_info(),
+            fee
+        )?;
+
+        experiment_state.total_dev_fees_collected = experiment_state
+            .total_dev_fees_collected
+            .checked_add(fee)
+            .ok_or(CrazySolError::Overflow)?;
+    }
+
+    release_reactor_lamports(
+        reactor_state,
+        &ctx.accounts.owner.to_account_info(),
+        lamports_out
+    )?;
+
+    scientist_state.owned_pill = scientist_state.owned_pill
+        .checked_sub(pill_amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    reactor_state.pill_supply = reactor_state.pill_supply
+        .checked_sub(pill_amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    scientist_state.sync_yield_debt(reactor_state)?;
+
+    experiment_state.total_pills_dissolved = experiment_state
+        .total_pills_dissolved
+        .checked_add(pill_amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    experiment_state.total_sol_dissolved = experiment_state
+        .total_sol_dissolved
+        .checked_add(lamports_out)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(DissolveEvent {
+        scientist: ctx.accounts.owner.key(),
+        dissolved_pill: pill_amount,
+        lamports_out,
+        fee,
+        total_owned_pill: scientist_state.owned_pill,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
@@ -1,32 +1,32 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::{VAPORIZATION_PERCENTAGE}, errors::CrazySolError, events::DistillEvent, require_initialized, require_operational, states::{ExperimentState, LaboratoryState, ReactorState, ScientistState}};
+use crate::{constants::{VAPORIZATION_PERCENTAGE}, errors::CrazySolError, events::DistillEvent, release_reactor_lamports, require_initialized, require_not_paused, require_season_not_finalized, states::{ExperimentState, LaboratoryState, ReactorState, ScientistState}, withdrawable_reserves};
 
 #[derive(Accounts)]
 pub struct Distill<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub reactor_state: Account<'info, ReactorState>,
 
     #[account(
         mut,
-        seeds = [ExperimentState::SEED],
+        seeds = [ExperimentState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub experiment_state: Account<'info, ExperimentState>,
 
     #[account(
         mut,
-        seeds = [ScientistState::SEED, owner.key().as_ref()],
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
         bump,
         has_one = owner,
     )]
@@ -52,25 +52,23 @@ pub fn handle_distill(
     let current_ts = Clock::get()?.unix_timestamp;
 
     require_initialized(laboratory_state)?;
-    require_operational(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_WITHDRAWALS)?;
+    require_season_not_finalized(laboratory_state)?;
 
-    scientist_state.incubate_serum(laboratory_state, current_ts)?;
+    scientist_state.incubate_serum(reactor_state, current_ts)?;
     let mut yield_amount = scientist_state.distillable_yield;
     if yield_amount == 0 {
         return Err(CrazySolError::NoYield.into());
     }
 
-    let rent = Rent::get()?;
-    let min_balance = rent.minimum_balance(reactor_state.to_account_info().data_len());
-    let reactor_balance = reactor_state.to_account_info().lamports();
-    let available_balance = reactor_balance
-        .checked_sub(min_balance)
-        .ok_or(CrazySolError::Overflow)?;
+    let available_balance = withdrawable_reserves(reactor_state)?;
 
     if yield_amount > available_balance {
         yield_amount = available_balance;
     }
 
+    let distilled_yield = yield_amount;
+
     let fee = yield_amount
         .checked_mul(laboratory_state.containment_tax_bps as u64)
         .ok_or(CrazySolError::Overflow)?
@@ -82,19 +80,11 @@ pub fn handle_distill(
             .checked_sub(fee)
             .ok_or(CrazySolError::Overflow)?;
 
-        **reactor_state.to_account_info().try_borrow_mut_lamports()? = reactor_state
-            .to_account_info()
-            .lamports()
-            .checked_sub(fee)
-            .ok_or(CrazySolError::Overflow)?;
-
-        **ctx.accounts.big_pharma.to_account_info().try_borrow_mut_lamports()? = ctx
-            .accounts
-            .big_pharma
-            .to_account_info()
-            .lamports()
-            .checked_add(fee)
-            .ok_or(CrazySolError::Overflow)?;
+        release_reactor_lamports(
+            reactor_state,
+            &ctx.accounts.big_pharma.to_account_info(),
+            fee
+        )?;
 
         experiment_state.total_dev_fees_collected = experiment_state
             .total_dev_fees_collected
@@ -102,25 +92,19 @@ pub fn handle_distill(
             .ok_or(CrazySolError::Overflow)?;
     }
 
-    **reactor_state.to_account_info().try_borrow_mut_lamports()? = reactor_state
-        .to_account_info()
-        .lamports()
-        .checked_sub(yield_amount)
-        .ok_or(CrazySolError::Overflow)?;
-
-    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? = ctx
-        .accounts
-        .owner
-        .to_account_info()
-        .lamports()
-        .checked_add(yield_amount)
-        .ok_or(CrazySolError::Overflow)?;
+    release_reactor_lamports(
+        reactor_state,
+        &ctx.accounts.owner.to_account_info(),
+        yield_amount
+    )?;
 
     scientist_state.distillable_yield = scientist_state
         .distillable_yield
-        .checked_sub(yield_amount)
+        .checked_sub(distilled_yield)
         .ok_or(CrazySolError::Overflow)?;
 
+    reactor_state.settle_yield(distilled_yield)?;
+
     scientist_state.last_distillation_timestamp = current_ts;
 
     let pill_vaporized = scientist_state.owned_pill
@@ -141,6 +125,8 @@ pub fn handle_distill(
         .checked_sub(pill_vaporized)
         .ok_or(CrazySolError::Overflow)?;
 
+    scientist_state.sync_yield_debt(reactor_state)?;
+
     experiment_state.total_pills_vaporized = experiment_state
         .total_pills_vaporized
         .checked_add(pill_vaporized)
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::EmergencyWithdrawEvent, release_reactor_lamports, require_initialized, states::{LaboratoryState, ReactorState, ScientistState}, withdrawable_reserves};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_emergency_withdraw(
+    ctx: Context<EmergencyWithdraw>,
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require!(
+        laboratory_state.emergency_exit,
+        CrazySolError::EmergencyExitNotActive
+    );
+
+    scientist_state.incubate_serum(reactor_state, current_ts)?;
+
+    let yield_amount = scientist_state.distillable_yield;
+    let withdrawn_pill = scientist_state.owned_pill;
+
+    // After finalization the settlement rate is already fixed against a frozen supply.
+    let pill_payout = if laboratory_state.season_finalized {
+        reactor_state.settlement_payout(withdrawn_pill)?
+    } else {
+        let pooled_reserves = withdrawable_reserves(reactor_state)?
+            .saturating_sub(reactor_state.outstanding_yield);
+        reactor_state.pro_rata_payout(pooled_reserves, withdrawn_pill)?
+    };
+
+    let total_payout = yield_amount
+        .checked_add(pill_payout)
+        .ok_or(CrazySolError::Overflow)?;
+    if total_payout == 0 {
+        return Err(CrazySolError::NoYield.into());
+    }
+
+    scientist_state.distillable_yield = 0;
+    scientist_state.owned_pill = 0;
+    reactor_state.settle_yield(yield_amount)?;
+
+    if !laboratory_state.season_finalized {
+        reactor_state.pill_supply = reactor_state.pill_supply
+            .checked_sub(withdrawn_pill)
+            .ok_or(CrazySolError::Overflow)?;
+    }
+
+    scientist_state.sync_yield_debt(reactor_state)?;
+
+    release_reactor_lamports(
+        reactor_state,
+        &ctx.accounts.owner.to_account_info(),
+        total_payout
+    )?;
+
+    scientist_state.earned_sol = scientist_state.earned_sol
+        .checked_add(total_payout)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(EmergencyWithdrawEvent {
+        scientist: ctx.accounts.owner.key(),
+        yield_amount,
+        withdrawn_pill,
+        pill_payout,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::EnableEmergencyExitEvent, require_initialized, states::LaboratoryState};
+
+#[derive(Accounts)]
+pub struct EnableEmergencyExit<'info> {
+    #[account(
+        mut,
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+}
+
+pub fn handle_enable_emergency_exit(
+    ctx: Context<EnableEmergencyExit>,
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require!(
+        laboratory_state.emergency_lockdown,
+        CrazySolError::EmergencyLockdownRequired
+    );
+    require!(
+        !laboratory_state.emergency_exit,
+        CrazySolError::EmergencyExitActive
+    );
+
+    // Irreversible: once scientists start leaving, the game cannot resume.
+    laboratory_state.emergency_exit = true;
+
+    emit!(EnableEmergencyExitEvent {
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CrazySolError,
+    events::{UpdateBigPharmaEvent, UpdateContainmentTaxBpsEvent, UpdateGovernanceDelayEvent, UpdateReactionFormulaEvent},
+    require_initialized,
+    states::{LaboratoryState, ParameterChange, ParameterChangeKind, PendingChange, ReactorState},
+};
+
+#[derive(Accounts)]
+#[instruction(kind: ParameterChangeKind)]
+pub struct ExecutePendingChange<'info> {
+    #[account(
+        mut,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [PendingChange::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), &[kind as u8]],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    /// CHECK: Receives the rent of the executed change, validated against pending_change
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handle_execute_pending_change(
+    ctx: Context<ExecutePendingChange>,
+    _kind: ParameterChangeKind
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let pending_change = &ctx.accounts.pending_change;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+
+    require!(
+        current_ts >= pending_change.executable_at,
+        CrazySolError::ChangeNotReady
+    );
+
+    match pending_change.change {
+        ParameterChange::ReactionFormula(new_reaction_formula) => {
+            // Bounds and supply may have moved during the delay, so check against the current values.
+            laboratory_state.formula_bounds.check_reaction_formula(
+                &new_reaction_formula,
+                Some((&laboratory_state.reaction_formula, ctx.accounts.reactor_state.pill_supply))
+            )?;
+            laboratory_state.reaction_formula = new_reaction_formula;
+
+            emit!(UpdateReactionFormulaEvent {
+                new_reaction_formula,
+            });
+        }
+        ParameterChange::ContainmentTaxBps(new_containment_tax_bps) => {
+            let previous_containment_tax_bps = laboratory_state.containment_tax_bps;
+            laboratory_state.containment_tax_bps = new_containment_tax_bps;
+
+            emit!(UpdateContainmentTaxBpsEvent {
+                previous_containment_tax_bps,
+                new_containment_tax_bps,
+            });
+        }
+        ParameterChange::BigPharma(new_big_pharma) => {
+            let old_big_pharma = laboratory_state.big_pharma;
+            laboratory_state.big_pharma = new_big_pharma;
+
+            emit!(UpdateBigPharmaEvent {
+                old_big_pharma,
+                new_big_pharma,
+            });
+        }
+        ParameterChange::GovernanceDelay(new_governance_delay) => {
+            let previous_governance_delay = laboratory_state.governance_delay;
+            laboratory_state.governance_delay = new_governance_delay;
+
+            emit!(UpdateGovernanceDelayEvent {
+                previous_governance_delay,
+                new_governance_delay,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::{AccountMeta, Instruction}, program::invoke_signed},
+};
+
+use crate::{errors::CrazySolError, events::ExecuteProposalEvent, states::{Council, Proposal}};
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [Council::SEED, council.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        has_one = council,
+        seeds = [Proposal::SEED, council.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: Signing PDA of the council, only used as a CPI signer
+    #[account(
+        mut,
+        seeds = [Council::AUTHORITY_SEED, council.key().as_ref()],
+        bump = council.authority_bump
+    )]
+    pub council_authority: UncheckedAccount<'info>,
+}
+
+// Replays the stored instruction against this program with the council
+// authority as signer. Every account it references must be passed in
+// remaining_accounts, along with the program itself.
+pub fn handle_execute_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>
+) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    let council_authority = ctx.accounts.council_authority.key();
+
+    require!(!proposal.executed, CrazySolError::ProposalAlreadyExecuted);
+    require!(
+        proposal.council_version == council.version,
+        CrazySolError::StaleProposal
+    );
+    require!(
+        proposal.approval_count() >= council.threshold as u32,
+        CrazySolError::ThresholdNotMet
+    );
+
+    let mut account_metas = Vec::with_capacity(proposal.accounts.len());
+    for meta in proposal.accounts.iter() {
+        require!(
+            !meta.is_signer || meta.pubkey == council_authority,
+            CrazySolError::InvalidProposal
+        );
+        account_metas.push(AccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        });
+    }
+
+    let instruction = Instruction {
+        program_id: *ctx.program_id,
+        accounts: account_metas,
+        data: proposal.instruction_data.clone(),
+    };
+
+    // Persist the executed flag before the CPI so the proposal cannot be replayed from within it.
+    proposal.executed = true;
+    proposal.exit(ctx.program_id)?;
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.council_authority.to_account_info());
+
+    let council_key = council.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Council::AUTHORITY_SEED,
+        council_key.as_ref(),
+        &[council.authority_bump],
+    ]];
+
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+    emit!(ExecuteProposalEvent {
+        council: council_key,
+        proposal: proposal.key(),
+        id: proposal.id,
+    });
+
+    Ok(())
+}
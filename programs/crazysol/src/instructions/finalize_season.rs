@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::FinalizeSeasonEvent, require_initialized, require_season_not_finalized, states::{LaboratoryState, ReactorState}, withdrawable_reserves};
+
+#[derive(Accounts)]
+pub struct FinalizeSeason<'info> {
+    #[account(
+        mut,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handle_finalize_season(
+    ctx: Context<FinalizeSeason>,
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_season_not_finalized(laboratory_state)?;
+    require!(
+        current_ts >= laboratory_state.season_schedule.settlement_ts,
+        CrazySolError::SettlementNotReached
+    );
+
+    // Yield already distributed stays owed to its holders; only the remainder is split per Pill.
+    let settlement_reserves = withdrawable_reserves(reactor_state)?
+        .saturating_sub(reactor_state.outstanding_yield);
+    let final_lamports_per_pill = reactor_state.compute_final_lamports_per_pill(settlement_reserves)?;
+
+    reactor_state.final_lamports_per_pill = final_lamports_per_pill;
+    laboratory_state.season_finalized = true;
+
+    emit!(FinalizeSeasonEvent {
+        final_pill_supply: reactor_state.pill_supply,
+        settlement_reserves,
+        outstanding_yield: reactor_state.outstanding_yield,
+        final_lamports_per_pill,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
@@ -1,19 +1,19 @@
 use anchor_lang::prelude::*;
 use crate::{
-    errors::CrazySolError,
+    compute_pill_potency,
     states::{LaboratoryState, ReactorState},
 };
 
 #[derive(Accounts)]
 pub struct GetPillPotency<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub reactor_state: Account<'info, ReactorState>,
@@ -21,24 +21,12 @@ pub struct GetPillPotency<'info> {
 
 pub fn handle_get_pill_potency(
     ctx: Context<GetPillPotency>,
-) -> Result<u64> {
+) -> Result<u128> {
     let laboratory_state = &ctx.accounts.laboratory_state;
     let reactor_state = &ctx.accounts.reactor_state;
 
-    let p0 = laboratory_state.reaction_formula.bonding_curve_p0 as u128;
-    let m = laboratory_state.reaction_formula.bonding_curve_m as u128;
-    let current_supply = reactor_state.pill_supply;
-
-    let price = p0
-        .checked_add(
-            m.checked_mul(current_supply)
-                .ok_or(CrazySolError::Overflow)?
-        )
-        .ok_or(CrazySolError::Overflow)?;
-
-    let price_u64: u64 = price
-        .try_into()
-        .map_err(|_| CrazySolError::Overflow)?;
-
-    Ok(price_u64)
+    compute_pill_potency(
+        reactor_state.pill_supply,
+        &laboratory_state.reaction_formula.bonding_curve
+    )
 }
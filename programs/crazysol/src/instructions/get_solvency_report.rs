@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{
+    compute_pill_potency,
+    states::{LaboratoryState, ReactorState},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SolvencyReport {
+    pub reactor_lamports: u64,
+    pub rent_minimum: u64,
+    pub sol_reserves: u64,
+    pub total_lamports_in: u64,
+    pub total_lamports_out: u64,
+    pub pill_supply: u128,
+    pub pill_potency: u128,
+    pub outstanding_yield: u64,
+    pub undistributed_yield: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetSolvencyReport<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+}
+
+pub fn handle_get_solvency_report(
+    ctx: Context<GetSolvencyReport>,
+) -> Result<SolvencyReport> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let reactor_state = &ctx.accounts.reactor_state;
+    let reactor_info = reactor_state.to_account_info();
+
+    let rent = Rent::get()?;
+
+    Ok(SolvencyReport {
+        reactor_lamports: reactor_info.lamports(),
+        rent_minimum: rent.minimum_balance(reactor_info.data_len()),
+        sol_reserves: reactor_state.sol_reserves,
+        total_lamports_in: reactor_state.total_lamports_in,
+        total_lamports_out: reactor_state.total_lamports_out,
+        pill_supply: reactor_state.pill_supply,
+        pill_potency: compute_pill_potency(
+            reactor_state.pill_supply,
+            &laboratory_state.reaction_formula.bonding_curve
+        )?,
+        outstanding_yield: reactor_state.outstanding_yield,
+        undistributed_yield: reactor_state.undistributed_yield,
+    })
+}
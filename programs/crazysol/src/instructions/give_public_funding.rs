@@ -1,19 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::CrazySolError, events::GiveSomePcrazyEvent, require_initialized, LaboratoryState, ReactorState, ScientistState};
+use crate::{credit_public_funding, errors::CrazySolError, events::GiveSomePcrazyEvent, require_initialized, require_innoculation_not_happened, require_role, LaboratoryState, ReactorState, RoleGrant};
 
 #[derive(Accounts)]
 pub struct GivePublicFunding<'info> {
     #[account(
-        has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub reactor_state: Account<'info, ReactorState>,
@@ -22,8 +21,14 @@ pub struct GivePublicFunding<'info> {
     #[account(mut)]
     pub receiver: UncheckedAccount<'info>,
 
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(mut)]
-    pub director: Signer<'info>,
+    pub authority: Signer<'info>,
 }
 
 pub fn handle_give_public_funding(
@@ -35,46 +40,35 @@ pub fn handle_give_public_funding(
     let reactor_state = &mut ctx.accounts.reactor_state;
 
     require_initialized(laboratory_state)?;
+    require_innoculation_not_happened(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::DISTRIBUTOR
+    )?;
 
     if reactor_state.owned_pcrazy < amount {
         return Err(CrazySolError::InsufficientpCRAZY.into());
     }
 
-    let (expected_pda, _) = Pubkey::find_program_address(
-        &[ScientistState::SEED, scientist.as_ref()],
+    let new_scientist_pcrazy = credit_public_funding(
         ctx.program_id,
-    );
-
-    if expected_pda != ctx.accounts.receiver.key() {
-        return Err(CrazySolError::InvalidScientistAddress.into());
-    }
-
-    let mut scientist_data = ctx.accounts.receiver.try_borrow_mut_data()?;
-
-    let mut scientist_state = ScientistState::try_from_slice(&mut scientist_data)
-        .map_err(|_| CrazySolError::InvalidScientist)?;
-
-    require!(
-        scientist_state.owner == scientist,
-        CrazySolError::InvalidScientist
-    );
-
-    scientist_state.owned_pcrazy = scientist_state
-        .owned_pcrazy
-        .checked_add(amount)
-        .ok_or(CrazySolError::Overflow)?;
+        laboratory_state.laboratory_id,
+        &ctx.accounts.receiver.to_account_info(),
+        scientist,
+        amount
+    )?;
 
     reactor_state.owned_pcrazy = reactor_state
         .owned_pcrazy
         .checked_sub(amount)
         .ok_or(CrazySolError::Overflow)?;
 
-    scientist_state.serialize(&mut *scientist_data)?;
-
     emit!(GiveSomePcrazyEvent {
         scientist,
         amount,
-        new_scientist_pcrazy: scientist_state.owned_pcrazy,
+        new_scientist_pcrazy,
         new_available_pcrazy_amount: reactor_state.owned_pcrazy,
     });
 
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{credit_public_funding, errors::CrazySolError, events::{GivePublicFundingBatchEvent, GiveSomePcrazyEvent}, require_initialized, require_innoculation_not_happened, require_role, LaboratoryState, ReactorState, RoleGrant};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PublicFundingEntry {
+    pub scientist: Pubkey,
+    pub amount: u64,
+}
+
+// Receivers are passed as remaining accounts, one per entry and in the same order.
+#[derive(Accounts)]
+pub struct GivePublicFundingBatch<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_give_public_funding_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, GivePublicFundingBatch<'info>>,
+    entries: Vec<PublicFundingEntry>,
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let remaining_accounts = &ctx.remaining_accounts;
+
+    require_initialized(laboratory_state)?;
+    require_innoculation_not_happened(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::DISTRIBUTOR
+    )?;
+
+    require!(
+        !entries.is_empty(),
+        CrazySolError::EmptyFundingBatch
+    );
+    require!(
+        remaining_accounts.len() == entries.len(),
+        CrazySolError::MissingAccount
+    );
+
+    let total_amount = entries
+        .iter()
+        .try_fold(0u64, |total, entry| total.checked_add(entry.amount))
+        .ok_or(CrazySolError::Overflow)?;
+
+    if reactor_state.owned_pcrazy < total_amount {
+        return Err(CrazySolError::InsufficientpCRAZY.into());
+    }
+
+    for (entry, receiver) in entries.iter().zip(remaining_accounts.iter()) {
+        reactor_state.owned_pcrazy = reactor_state
+            .owned_pcrazy
+            .checked_sub(entry.amount)
+            .ok_or(CrazySolError::Overflow)?;
+
+        let new_scientist_pcrazy = credit_public_funding(
+            ctx.program_id,
+            laboratory_state.laboratory_id,
+            receiver,
+            entry.scientist,
+            entry.amount
+        )?;
+
+        emit!(GiveSomePcrazyEvent {
+            scientist: entry.scientist,
+            amount: entry.amount,
+            new_scientist_pcrazy,
+            new_available_pcrazy_amount: reactor_state.owned_pcrazy,
+        });
+    }
+
+    emit!(GivePublicFundingBatchEvent {
+        recipients: entries.len() as u32,
+        total_amount,
+        new_available_pcrazy_amount: reactor_state.owned_pcrazy,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::GrantRoleEvent, require_initialized, require_role_admin_for, states::{LaboratoryState, RoleGrant}};
+
+#[derive(Accounts)]
+#[instruction(grantee: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoleGrant::SIZE,
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), grantee.as_ref()],
+        bump
+    )]
+    pub grantee_role_grant: Account<'info, RoleGrant>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_grant_role(
+    ctx: Context<GrantRole>,
+    grantee: Pubkey,
+    roles: u8
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let authority = ctx.accounts.authority.key();
+
+    require_initialized(laboratory_state)?;
+    require_role_admin_for(
+        laboratory_state,
+        &authority,
+        ctx.accounts.role_grant.as_ref(),
+        roles
+    )?;
+
+    let grantee_role_grant = &mut ctx.accounts.grantee_role_grant;
+    grantee_role_grant.grantee = grantee;
+    grantee_role_grant.roles |= roles;
+    grantee_role_grant.updated_by = authority;
+    grantee_role_grant.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(GrantRoleEvent {
+        grantee,
+        granted_roles: roles,
+        roles: grantee_role_grant.roles,
+        granted_by: authority,
+    });
+
+    Ok(())
+}
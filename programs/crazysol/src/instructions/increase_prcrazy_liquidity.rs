@@ -1,25 +1,30 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::CrazySolError, events::IncreasepCRAZYLiquidityEvent, require_initialized, require_innoculation_not_happened, states::{LaboratoryState, ReactorState}};
+use crate::{errors::CrazySolError, events::IncreasepCRAZYLiquidityEvent, require_initialized, require_role, require_innoculation_not_happened, states::{LaboratoryState, ReactorState, RoleGrant}};
 
 #[derive(Accounts)]
 pub struct IncreasepCRAZYLiquidity<'info> {
     #[account(
-        has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub reactor_state: Account<'info, ReactorState>,
 
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(mut)]
-    pub director: Signer<'info>,
+    pub authority: Signer<'info>,
 }
 
 pub fn handle_increase_pcrazy_liquidity(
@@ -30,6 +35,12 @@ pub fn handle_increase_pcrazy_liquidity(
     let reactor_state = &mut ctx.accounts.reactor_state;
 
     require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::TREASURER
+    )?;
     require_innoculation_not_happened(laboratory_state)?;
 
     let previous_available_pcrazy = reactor_state.owned_pcrazy;
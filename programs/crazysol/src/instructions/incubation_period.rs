@@ -1,18 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::{PCRAZY_DAILY_STREAK_BASE_REWARDS, SECONDS_IN_24H}, errors::CrazySolError, events::IncubationPeriodEvent, require_initialized, require_operational, require_innoculation_not_happened, states::{LaboratoryState, ReactorState, ScientistState}};
+use crate::{constants::{PCRAZY_DAILY_STREAK_BASE_REWARDS, SECONDS_IN_24H}, errors::CrazySolError, events::IncubationPeriodEvent, require_initialized, require_injection_window, require_not_paused, require_innoculation_not_happened, states::{LaboratoryState, ReactorState, ScientistState}};
 
 #[derive(Accounts)]
 pub struct IncubationPeriod<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
         mut,
-        seeds = [ScientistState::SEED, owner.key().as_ref()],
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
         bump,
         has_one = owner @ CrazySolError::Unauthorized,
     )]
@@ -20,7 +20,7 @@ pub struct IncubationPeriod<'info> {
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub reactor_state: Account<'info, ReactorState>,
@@ -38,7 +38,8 @@ pub fn handle_incubation_period(
     let current_time = Clock::get()?.unix_timestamp;
 
     require_initialized(laboratory_state)?;
-    require_operational(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_STREAKS)?;
+    require_injection_window(laboratory_state, current_time)?;
     require_innoculation_not_happened(laboratory_state)?;
 
     let time_since_last_streak = if scientist_account.last_streak_timestamp == 0 {
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{events::InitializeEvent, states::{LaboratoryState, ReactorState, ExperimentState, ReactionFormula}, utils::require_not_initialized};
+use crate::{events::InitializeEvent, states::{LaboratoryConfig, LaboratoryState, PcrazyTransferPolicy, ReactorState, ExperimentState}, utils::require_not_initialized};
 
 #[derive(Accounts)]
+#[instruction(laboratory_id: u64)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub director: Signer<'info>,
@@ -11,7 +12,7 @@ pub struct Initialize<'info> {
         init,
         payer = director,
         space = LaboratoryState::SIZE,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
@@ -20,7 +21,7 @@ pub struct Initialize<'info> {
         init,
         payer = director,
         space = ReactorState::SIZE,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub reactor: Account<'info, ReactorState>,
@@ -29,7 +30,7 @@ pub struct Initialize<'info> {
         init,
         payer = director,
         space = ExperimentState::SIZE,
-        seeds = [ExperimentState::SEED],
+        seeds = [ExperimentState::SEED, laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub experiment_state: Account<'info, ExperimentState>,
@@ -39,10 +40,11 @@ pub struct Initialize<'info> {
 
 pub fn handle_initialize(
     ctx: Context<Initialize>,
+    laboratory_id: u64,
     big_pharma: Pubkey,
-    containment_tax_bps: u16,
     owned_pcrazy: u64,
-    external_reactor: Pubkey
+    external_reactor: Pubkey,
+    config: LaboratoryConfig
 ) -> Result<()> {
     let laboratory_state = &mut ctx.accounts.laboratory_state;
     let reactor_state = &mut ctx.accounts.reactor;
@@ -50,26 +52,41 @@ pub fn handle_initialize(
 
     require_not_initialized(laboratory_state)?;
 
+    config.validate()?;
+
+    laboratory_state.laboratory_id = laboratory_id;
     laboratory_state.director = ctx.accounts.director.key();
     laboratory_state.big_pharma = big_pharma;
-    laboratory_state.containment_tax_bps = containment_tax_bps;
+    laboratory_state.containment_tax_bps = config.containment_tax_bps;
     laboratory_state.is_initialized = true;
     laboratory_state.emergency_lockdown = false;
+    laboratory_state.pause_flags = 0;
+    laboratory_state.emergency_exit = false;
+    laboratory_state.crazy_mint = Pubkey::default();
+    laboratory_state.innoculated_at = 0;
+    laboratory_state.vesting_schedule = config.vesting_schedule;
+    laboratory_state.pcrazy_transfer_policy = PcrazyTransferPolicy::default();
     laboratory_state.innoculation_happened = false;
-
-    laboratory_state.reaction_formula = ReactionFormula {
-        bonding_curve_m: 30,
-        bonding_curve_p0: 100_000,
-        reward_rate_per_pill: 2_222,
-        min_daily_yield_bps: 800,
-        first_injection_bonus: 10,
-        first_mutation_bonus: 15,
-    };
+    laboratory_state.pending_director = None;
+    laboratory_state.governance_delay = config.governance_delay;
+    laboratory_state.max_containment_tax_bps = config.max_containment_tax_bps;
+    laboratory_state.min_injection_lamports = config.min_injection_lamports;
+    laboratory_state.reaction_formula = config.reaction_formula;
+    laboratory_state.formula_bounds = config.formula_bounds;
+    laboratory_state.research_reward_schedule = config.research_reward_schedule;
+    laboratory_state.season_schedule = config.season_schedule;
+    laboratory_state.season_finalized = false;
 
     reactor_state.sol_reserves = 0;
     reactor_state.owned_pcrazy = owned_pcrazy;
     reactor_state.pill_supply = 0;
     reactor_state.external_reactor = external_reactor;
+    reactor_state.acc_yield_per_pill = 0;
+    reactor_state.undistributed_yield = 0;
+    reactor_state.outstanding_yield = 0;
+    reactor_state.total_lamports_in = 0;
+    reactor_state.total_lamports_out = 0;
+    reactor_state.final_lamports_per_pill = 0;
 
     experiment_state.total_dev_fees_collected = 0;
     experiment_state.total_pills_vaporized = 0;
@@ -79,11 +96,14 @@ pub fn handle_initialize(
     experiment_state.total_sol_injected = 0;
     experiment_state.total_scientists = 0;
     experiment_state.total_scientists_recruited = 0;
+    experiment_state.total_pills_dissolved = 0;
+    experiment_state.total_sol_dissolved = 0;
 
     emit!(InitializeEvent {
+        laboratory_id,
         director: laboratory_state.director,
         big_pharma,
-        containment_tax_bps,
+        containment_tax_bps: laboratory_state.containment_tax_bps,
         owned_pcrazy,
         external_reactor,
         reaction_formula: laboratory_state.reaction_formula,
@@ -4,39 +4,39 @@ use anchor_lang::{
 };
 
 use crate::{
-    constants::{PCRAZY_INJECTION_REWARDS, RESEARCH_REWARD_PERCENTAGES},
+    constants::PCRAZY_INJECTION_REWARDS,
     errors::CrazySolError,
     events::InjectEvent,
-    require_initialized, require_operational,
+    require_initialized, require_injection_window, require_not_paused,
     states::{LaboratoryState, ReactorState, ExperimentState, ScientistState},
-    utils::compute_rate_of_centrifugation,
+    utils::{compute_rate_of_centrifugation, verify_slippage_tolerance},
 };
 
 #[derive(Accounts)]
 pub struct Inject<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub reactor_state: Account<'info, ReactorState>,
 
     #[account(
         mut,
-        seeds = [ExperimentState::SEED],
+        seeds = [ExperimentState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub experiment_state: Account<'info, ExperimentState>,
 
     #[account(
         mut,
-        seeds = [ScientistState::SEED, owner.key().as_ref()],
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
         bump,
         has_one = owner,
     )]
@@ -59,6 +59,8 @@ pub struct Inject<'info> {
 pub fn handle_inject<'info>(
     ctx: Context<'_, '_, '_, 'info, Inject<'info>>,
     deposit: u64,
+    min_pills_out: u128,
+    max_price_per_pill: Option<u64>,
 ) -> Result<()> {
     let laboratory_state = &ctx.accounts.laboratory_state;
     let scientist_state = &mut ctx.accounts.scientist_state;
@@ -69,11 +71,12 @@ pub fn handle_inject<'info>(
     let original_deposit = deposit;
 
     require_initialized(laboratory_state)?;
-    require_operational(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_DEPOSITS)?;
+    require_injection_window(laboratory_state, current_ts)?;
 
-    scientist_state.incubate_serum(laboratory_state, current_ts)?;
+    scientist_state.incubate_serum(reactor_state, current_ts)?;
 
-    if deposit < 50_000_000 {
+    if deposit < laboratory_state.min_injection_lamports {
         return Err(CrazySolError::InjectionTooSmall.into());
     }
 
@@ -109,26 +112,36 @@ pub fn handle_inject<'info>(
     let mut current_recruiter = scientist_state.recruiter;
     let remaining_accounts = &ctx.remaining_accounts;
     let mut account_index = 0;
-    
-    for percentage in RESEARCH_REWARD_PERCENTAGES.iter() {
+    let mut total_research_rewards: u64 = 0;
+
+    // With referral payouts paused the whole deposit goes to the reactor.
+    let research_reward_schedule: &[u16] = if laboratory_state.is_paused(LaboratoryState::PAUSE_REFERRAL_PAYOUTS) {
+        &[]
+    } else {
+        &laboratory_state.research_reward_schedule
+    };
+
+    // Nothing past the last paying level is walked, so those accounts need not be passed.
+    let paid_levels = research_reward_schedule
+        .iter()
+        .rposition(|reward_bps| *reward_bps > 0)
+        .map_or(0, |level| level + 1);
+
+    for reward_bps in research_reward_schedule[..paid_levels].iter() {
         let research_reward = remaining_deposit
-            .checked_mul(*percentage as u64)
+            .checked_mul(*reward_bps as u64)
             .ok_or(CrazySolError::Overflow)?
-            .checked_div(100)
+            .checked_div(10_000)
             .ok_or(CrazySolError::Overflow)?;
-        
-        if research_reward == 0 {
-            continue;
-        }
 
         if let Some(recruiter_pubkey) = current_recruiter {
             let (expected_pda, _) = Pubkey::find_program_address(
-                &[ScientistState::SEED, recruiter_pubkey.as_ref()],
+                &[ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), recruiter_pubkey.as_ref()],
                 ctx.program_id,
             );
 
             require!(
-                account_index + 1 < remaining_accounts.len(),
+                account_index < remaining_accounts.len(),
                 CrazySolError::MissingAccount
             );
 
@@ -140,35 +153,42 @@ pub fn handle_inject<'info>(
                 CrazySolError::InvalidRecruiter
             );
 
-            let recruiter_wallet = &remaining_accounts[account_index];
-            account_index += 1;
+            let mut recruiter_state = ScientistState::try_deserialize(&mut recruiter_pda_account.try_borrow_data()?.as_ref())?;
 
             require!(
-                recruiter_wallet.key() == recruiter_pubkey,
+                recruiter_state.owner == recruiter_pubkey,
                 CrazySolError::InvalidRecruiter
             );
 
-            invoke(
-                &transfer(
-                    ctx.accounts.owner.key,
-                    recruiter_wallet.key,
-                    research_reward,
-                ),
-                &[
-                    ctx.accounts.owner.to_account_info(),
-                    recruiter_wallet.to_account_info(),
-                ],
-            )?;
+            current_recruiter = recruiter_state.recruiter;
 
-            {
-                let mut recruiter_data = recruiter_pda_account.try_borrow_mut_data()?;
-                let mut recruiter_state = ScientistState::try_deserialize(&mut recruiter_data.as_ref())?;
+            // A zero-reward level is only read to find the next recruiter.
+            if research_reward > 0 {
+                require!(
+                    account_index < remaining_accounts.len(),
+                    CrazySolError::MissingAccount
+                );
+
+                let recruiter_wallet = &remaining_accounts[account_index];
+                account_index += 1;
 
                 require!(
-                    recruiter_state.owner == recruiter_pubkey,
+                    recruiter_wallet.key() == recruiter_pubkey,
                     CrazySolError::InvalidRecruiter
                 );
 
+                invoke(
+                    &transfer(
+                        ctx.accounts.owner.key,
+                        recruiter_wallet.key,
+                        research_reward,
+                    ),
+                    &[
+                        ctx.accounts.owner.to_account_info(),
+                        recruiter_wallet.to_account_info(),
+                    ],
+                )?;
+
                 recruiter_state.earned_sol = recruiter_state.earned_sol
                     .checked_add(research_reward)
                     .ok_or(CrazySolError::Overflow)?;
@@ -176,11 +196,9 @@ pub fn handle_inject<'info>(
                     .checked_add(research_reward)
                     .ok_or(CrazySolError::Overflow)?;
 
-                current_recruiter = recruiter_state.recruiter;
-
-                recruiter_state.try_serialize(&mut recruiter_data.as_mut())?;
+                recruiter_state.try_serialize(&mut recruiter_pda_account.try_borrow_mut_data()?.as_mut())?;
             }
-        } else {
+        } else if research_reward > 0 {
             invoke(
                 &transfer(
                     ctx.accounts.owner.key,
@@ -192,24 +210,16 @@ pub fn handle_inject<'info>(
                     ctx.accounts.big_pharma.to_account_info(),
                 ],
             )?;
-            current_recruiter = None;
         }
 
-        experiment_state.total_research_fees_collected = experiment_state
-            .total_research_fees_collected
+        total_research_rewards = total_research_rewards
             .checked_add(research_reward)
             .ok_or(CrazySolError::Overflow)?;
     }
 
-    let total_research_percentage: u64 = RESEARCH_REWARD_PERCENTAGES
-        .iter()
-        .map(|&p| p as u64)
-        .sum();
-
-    let total_research_rewards = remaining_deposit
-        .checked_mul(total_research_percentage)
-        .ok_or(CrazySolError::Overflow)?
-        .checked_div(100)
+    experiment_state.total_research_fees_collected = experiment_state
+        .total_research_fees_collected
+        .checked_add(total_research_rewards)
         .ok_or(CrazySolError::Overflow)?;
 
     remaining_deposit = remaining_deposit
@@ -233,9 +243,8 @@ pub fn handle_inject<'info>(
     let new_pill = compute_rate_of_centrifugation(
         reactor_state.pill_supply, 
         remaining_deposit.into(),
-        laboratory_state.reaction_formula.bonding_curve_p0,
-        laboratory_state.reaction_formula.bonding_curve_m
-    );
+        &laboratory_state.reaction_formula.bonding_curve
+    )?;
     
     let mut total_new_pill = new_pill;
 
@@ -251,6 +260,14 @@ pub fn handle_inject<'info>(
             .ok_or(CrazySolError::Overflow)?;
     }
 
+    verify_slippage_tolerance(
+        remaining_deposit,
+        new_pill,
+        total_new_pill,
+        min_pills_out,
+        max_price_per_pill
+    )?;
+
     scientist_state.owned_pill = scientist_state.owned_pill
         .checked_add(total_new_pill)
         .ok_or(CrazySolError::Overflow)?;
@@ -259,6 +276,9 @@ pub fn handle_inject<'info>(
         .checked_add(total_new_pill)
         .ok_or(CrazySolError::Overflow)?;
 
+    scientist_state.sync_yield_debt(reactor_state)?;
+    scientist_state.record_curve_cost(remaining_deposit)?;
+
     scientist_state.sol_injected = scientist_state.sol_injected
         .checked_add(original_deposit)
         .ok_or(CrazySolError::Overflow)?;
@@ -1,17 +1,32 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
 
-use crate::{errors::CrazySolError, events::InnoculateEvent, require_initialized, require_innoculation_not_happened, states::LaboratoryState};
+use crate::{constants::MAX_CRAZY_DECIMALS, errors::CrazySolError, events::InnoculateEvent, require_initialized, require_innoculation_not_happened, states::{LaboratoryState, ReactorState}};
 
 #[derive(Accounts)]
 pub struct Innoculate<'info> {
     #[account(
         mut,
         has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
+    #[account(
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        constraint = crazy_mint.mint_authority == Some(reactor_state.key()).into() @ CrazySolError::InvalidCrazyMint,
+        constraint = crazy_mint.freeze_authority.is_none() @ CrazySolError::InvalidCrazyMint,
+        constraint = crazy_mint.supply == 0 @ CrazySolError::InvalidCrazyMint,
+        constraint = crazy_mint.decimals <= MAX_CRAZY_DECIMALS @ CrazySolError::InvalidCrazyMint,
+    )]
+    pub crazy_mint: Account<'info, Mint>,
+
     #[account(mut)]
     pub director: Signer<'info>,
 }
@@ -26,9 +41,12 @@ pub fn handle_innoculate(
     require_innoculation_not_happened(laboratory_state)?;
 
     laboratory_state.innoculation_happened = true;
+    laboratory_state.crazy_mint = ctx.accounts.crazy_mint.key();
+    laboratory_state.innoculated_at = timestamp;
 
     emit!(InnoculateEvent {
         innoculation_happened: true,
+        crazy_mint: laboratory_state.crazy_mint,
         timestamp,
     });
 
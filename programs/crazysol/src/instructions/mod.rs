@@ -1,35 +1,99 @@
 pub mod innoculate;
 pub mod inject;
 pub mod distill;
+pub mod dissolve;
 pub mod mutate;
 pub mod incubation_period;
+pub mod accept_director;
+pub mod approve_proposal;
+pub mod cancel_director_proposal;
+pub mod cancel_pending_change;
+pub mod claim_crazy;
+pub mod claim_public_funding;
+pub mod claim_referral_code;
+pub mod claim_settlement;
+pub mod close_funding_campaign;
+pub mod create_council;
+pub mod create_funding_campaign;
+pub mod create_proposal;
 pub mod deposit_from_external_reactor;
+pub mod emergency_withdraw;
+pub mod enable_emergency_exit;
+pub mod execute_pending_change;
+pub mod execute_proposal;
+pub mod finalize_season;
 pub mod get_distillable_output;
 pub mod get_pill_potency;
+pub mod get_solvency_report;
 pub mod give_public_funding;
+pub mod give_public_funding_batch;
+pub mod grant_role;
 pub mod increase_prcrazy_liquidity;
 pub mod initialize;
+pub mod propose_director;
+pub mod reconcile_reserves;
 pub mod register_scientist;
+pub mod rename_scientist;
+pub mod revoke_role;
+pub mod set_pause_flags;
 pub mod switch_emergency_lockdown;
-pub mod replace_director;
 pub mod update_big_pharma;
 pub mod update_containment_tax_bps;
+pub mod update_council;
+pub mod update_formula_bounds;
+pub mod update_governance_delay;
+pub mod transfer_pcrazy;
+pub mod update_pcrazy_transfer_policy;
 pub mod update_reaction_formula;
+pub mod update_research_reward_schedule;
+pub mod update_season_schedule;
 
 pub use innoculate::*;
 pub use inject::*;
 pub use distill::*;
+pub use dissolve::*;
 pub use mutate::*;
 pub use incubation_period::*;
+pub use accept_director::*;
+pub use approve_proposal::*;
+pub use cancel_director_proposal::*;
+pub use cancel_pending_change::*;
+pub use claim_crazy::*;
+pub use claim_public_funding::*;
+pub use claim_referral_code::*;
+pub use claim_settlement::*;
+pub use close_funding_campaign::*;
+pub use create_council::*;
+pub use create_funding_campaign::*;
+pub use create_proposal::*;
 pub use deposit_from_external_reactor::*;
+pub use emergency_withdraw::*;
+pub use enable_emergency_exit::*;
+pub use execute_pending_change::*;
+pub use execute_proposal::*;
+pub use finalize_season::*;
 pub use get_distillable_output::*;
 pub use get_pill_potency::*;
+pub use get_solvency_report::*;
 pub use give_public_funding::*;
+pub use give_public_funding_batch::*;
+pub use grant_role::*;
 pub use increase_prcrazy_liquidity::*;
 pub use initialize::*;
+pub use propose_director::*;
+pub use reconcile_reserves::*;
 pub use register_scientist::*;
+pub use rename_scientist::*;
+pub use revoke_role::*;
+pub use set_pause_flags::*;
 pub use switch_emergency_lockdown::*;
-pub use replace_director::*;
 pub use update_big_pharma::*;
 pub use update_containment_tax_bps::*;
+pub use update_council::*;
+pub use update_formula_bounds::*;
+pub use update_governance_delay::*;
+pub use transfer_pcrazy::*;
+pub use update_pcrazy_transfer_policy::*;
 pub use update_reaction_formula::*;
+pub use update_research_reward_schedule::*;
+pub use update_season_schedule::*;
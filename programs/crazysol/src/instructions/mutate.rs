@@ -1,32 +1,32 @@
 use anchor_lang::prelude::*;
 
-use crate::{compute_rate_of_centrifugation, constants::{PCRAZY_MUTATION_REWARDS}, errors::CrazySolError, events::MutateEvent, require_initialized, require_operational, states::{LaboratoryState, ReactorState, ExperimentState, ScientistState}};
+use crate::{compute_rate_of_centrifugation, constants::{PCRAZY_MUTATION_REWARDS}, errors::CrazySolError, events::MutateEvent, require_initialized, require_injection_window, require_not_paused, verify_slippage_tolerance, states::{LaboratoryState, ReactorState, ExperimentState, ScientistState}};
 
 #[derive(Accounts)]
 pub struct Mutate<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub reactor_state: Account<'info, ReactorState>,
 
     #[account(
         mut,
-        seeds = [ExperimentState::SEED],
+        seeds = [ExperimentState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub experiment_state: Account<'info, ExperimentState>,
 
     #[account(
         mut,
-        seeds = [ScientistState::SEED, owner.key().as_ref()],
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
         bump,
         has_one = owner,
     )]
@@ -38,6 +38,8 @@ pub struct Mutate<'info> {
 
 pub fn handle_mutate(
     ctx: Context<Mutate>,
+    min_pills_out: u128,
+    max_price_per_pill: Option<u64>,
 ) -> Result<()> {
     let laboratory_state = &ctx.accounts.laboratory_state;
     let scientist_state = &mut ctx.accounts.scientist_state;
@@ -46,9 +48,10 @@ pub fn handle_mutate(
     let current_ts = Clock::get()?.unix_timestamp;
 
     require_initialized(laboratory_state)?;
-    require_operational(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_COMPOUNDING)?;
+    require_injection_window(laboratory_state, current_ts)?;
 
-    scientist_state.incubate_serum(laboratory_state, current_ts)?;
+    scientist_state.incubate_serum(reactor_state, current_ts)?;
 
     let pending_yield = scientist_state.distillable_yield;
     if pending_yield == 0 {
@@ -60,12 +63,12 @@ pub fn handle_mutate(
         .checked_add(pending_yield)
         .ok_or(CrazySolError::Overflow)?;
 
-    let mut new_pill = compute_rate_of_centrifugation(
+    let curve_pill = compute_rate_of_centrifugation(
         reactor_state.pill_supply,
         pending_yield as u128,
-        laboratory_state.reaction_formula.bonding_curve_p0,
-        laboratory_state.reaction_formula.bonding_curve_m
-    );
+        &laboratory_state.reaction_formula.bonding_curve
+    )?;
+    let mut new_pill = curve_pill;
 
     if !scientist_state.is_first_mutation_done {
         let bonus = new_pill
@@ -77,6 +80,14 @@ pub fn handle_mutate(
         scientist_state.is_first_mutation_done = true;
     }
 
+    verify_slippage_tolerance(
+        pending_yield,
+        curve_pill,
+        new_pill,
+        min_pills_out,
+        max_price_per_pill
+    )?;
+
     if !laboratory_state.innoculation_happened {
         let pre_tge_crazy_rewards = if reactor_state.owned_pcrazy >= PCRAZY_MUTATION_REWARDS {
             PCRAZY_MUTATION_REWARDS
@@ -96,6 +107,7 @@ pub fn handle_mutate(
     }
 
     scientist_state.distillable_yield = 0;
+    reactor_state.settle_yield(pending_yield)?;
 
     scientist_state.owned_pill = scientist_state.owned_pill
         .checked_add(new_pill)
@@ -104,6 +116,9 @@ pub fn handle_mutate(
         .checked_add(new_pill)
         .ok_or(CrazySolError::Overflow)?;
 
+    scientist_state.sync_yield_debt(reactor_state)?;
+    scientist_state.record_curve_cost(pending_yield)?;
+
     scientist_state.last_distillation_timestamp = current_ts;
 
     emit!(MutateEvent {
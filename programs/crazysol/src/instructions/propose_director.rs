@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::ProposeDirectorEvent, require_initialized, states::LaboratoryState};
+
+#[derive(Accounts)]
+pub struct ProposeDirector<'info> {
+    #[account(
+        mut,
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+}
+
+pub fn handle_propose_director(
+    ctx: Context<ProposeDirector>,
+    new_director: Pubkey
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+
+    require!(
+        new_director != Pubkey::default() && new_director != laboratory_state.director,
+        CrazySolError::InvalidDirector
+    );
+
+    laboratory_state.pending_director = Some(new_director);
+
+    emit!(ProposeDirectorEvent {
+        director: laboratory_state.director,
+        pending_director: new_director,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::ReconcileReservesEvent, require_initialized, require_role, states::{LaboratoryState, ReactorState, RoleGrant}};
+
+#[derive(Accounts)]
+pub struct ReconcileReserves<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_reconcile_reserves(
+    ctx: Context<ReconcileReserves>
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let reactor_state = &mut ctx.accounts.reactor_state;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::TREASURER
+    )?;
+
+    let reactor_info = reactor_state.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(reactor_info.data_len());
+    let actual_reserves = reactor_info
+        .lamports()
+        .checked_sub(min_balance)
+        .ok_or(CrazySolError::Overflow)?;
+
+    // The discrepancy is booked like any other flow so that
+    // sol_reserves == total_lamports_in - total_lamports_out keeps holding.
+    let previous_sol_reserves = reactor_state.sol_reserves;
+    if actual_reserves >= previous_sol_reserves {
+        reactor_state.record_inflow(actual_reserves - previous_sol_reserves)?;
+    } else {
+        reactor_state.record_outflow(previous_sol_reserves - actual_reserves)?;
+    }
+
+    emit!(ReconcileReservesEvent {
+        previous_sol_reserves,
+        reconciled_sol_reserves: actual_reserves,
+        discrepancy: actual_reserves as i128 - previous_sol_reserves as i128,
+        timestamp
+    });
+
+    Ok(())
+}
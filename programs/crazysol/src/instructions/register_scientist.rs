@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::{constants::{PCRAZY_RECRUITMENT_REWARDS}, errors::CrazySolError, events::RegisterScientistEvent, require_initialized, require_operational, states::{ExperimentState, LaboratoryState, ReactorState, ScientistState}};
+use crate::{constants::{PCRAZY_RECRUITMENT_REWARDS}, errors::CrazySolError, events::RegisterScientistEvent, normalize_handle, normalize_username, require_initialized, require_not_paused, require_registration_open, states::{ExperimentState, LaboratoryState, ReactorState, ReferralCode, ScientistState, UsernameRecord}, username_seed};
 
 #[derive(Accounts)]
+#[instruction(username: String)]
 pub struct RegisterScientist<'info> {
     #[account(
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
@@ -13,21 +14,21 @@ pub struct RegisterScientist<'info> {
         init,
         payer = scientist,
         space = ScientistState::SIZE,
-        seeds = [ScientistState::SEED, scientist.key().as_ref()],
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), scientist.key().as_ref()],
         bump
     )]
     pub scientist_state: Account<'info, ScientistState>,
 
     #[account(
         mut,
-        seeds = [ExperimentState::SEED],
+        seeds = [ExperimentState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub experiment_state: Account<'info, ExperimentState>,
 
     #[account(
         mut,
-        seeds = [ReactorState::SEED],
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub reactor_state: Account<'info, ReactorState>,
@@ -36,6 +37,17 @@ pub struct RegisterScientist<'info> {
     #[account(mut)]
     pub recruiter_account: UncheckedAccount<'info>,
 
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
+    #[account(
+        init,
+        payer = scientist,
+        space = UsernameRecord::SIZE,
+        seeds = [UsernameRecord::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), username_seed(&username).as_ref()],
+        bump
+    )]
+    pub username_record: Account<'info, UsernameRecord>,
+
     pub system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -46,6 +58,7 @@ pub fn handle_register_scientist<'info>(
     ctx: Context<'_, '_, '_, 'info, RegisterScientist<'info>>,
     username: String,
     recruiter: Option<Pubkey>,
+    referral_code: Option<String>,
 ) -> Result<()> {
     let laboratory_state = &ctx.accounts.laboratory_state;
     let experiment_state = &mut ctx.accounts.experiment_state;
@@ -56,7 +69,42 @@ pub fn handle_register_scientist<'info>(
     let mut account_index = 0;
 
     require_initialized(laboratory_state)?;
-    require_operational(laboratory_state)?;
+    require_not_paused(laboratory_state, LaboratoryState::PAUSE_REGISTRATIONS)?;
+    require_registration_open(laboratory_state, Clock::get()?.unix_timestamp)?;
+
+    let recruiter = match referral_code {
+        Some(code) => {
+            let normalized_code = normalize_handle(
+                &code,
+                ReferralCode::MIN_CODE_LENGTH,
+                ReferralCode::MAX_CODE_LENGTH
+            )?;
+
+            let referral_code_account = ctx.accounts.referral_code
+                .as_ref()
+                .ok_or(CrazySolError::MissingAccount)?;
+
+            let (expected_referral_code_pda, _) = Pubkey::find_program_address(
+                &[ReferralCode::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), normalized_code.as_bytes()],
+                ctx.program_id
+            );
+
+            require!(
+                referral_code_account.key() == expected_referral_code_pda,
+                CrazySolError::InvalidReferralCode
+            );
+
+            if let Some(recruiter_key) = recruiter {
+                require!(
+                    recruiter_key == referral_code_account.owner,
+                    CrazySolError::InvalidReferralCode
+                );
+            }
+
+            Some(referral_code_account.owner)
+        }
+        None => recruiter,
+    };
 
     if let Some(recruiter_key) = recruiter {
         require!(
@@ -65,7 +113,7 @@ pub fn handle_register_scientist<'info>(
         );
 
         let (expected_pda, _) = Pubkey::find_program_address(
-            &[ScientistState::SEED, recruiter_key.as_ref()],
+            &[ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), recruiter_key.as_ref()],
             ctx.program_id
         );
 
@@ -93,18 +141,19 @@ pub fn handle_register_scientist<'info>(
             .checked_add(1)
             .ok_or(CrazySolError::Overflow)?;
 
-        if !laboratory_state.innoculation_happened {
-            if reactor_state.owned_pcrazy >= PCRAZY_RECRUITMENT_REWARDS {
-                reactor_state.owned_pcrazy = reactor_state.owned_pcrazy
-                    .checked_sub(PCRAZY_RECRUITMENT_REWARDS)
-                    .ok_or(CrazySolError::Overflow)?;
+        if !laboratory_state.innoculation_happened
+            && !laboratory_state.is_paused(LaboratoryState::PAUSE_REFERRAL_PAYOUTS)
+            && reactor_state.owned_pcrazy >= PCRAZY_RECRUITMENT_REWARDS
+        {
+            reactor_state.owned_pcrazy = reactor_state.owned_pcrazy
+                .checked_sub(PCRAZY_RECRUITMENT_REWARDS)
+                .ok_or(CrazySolError::Overflow)?;
 
-                recruiter_state.owned_pcrazy = recruiter_state.owned_pcrazy
-                    .checked_add(PCRAZY_RECRUITMENT_REWARDS)
-                    .ok_or(CrazySolError::Overflow)?;
+            recruiter_state.owned_pcrazy = recruiter_state.owned_pcrazy
+                .checked_add(PCRAZY_RECRUITMENT_REWARDS)
+                .ok_or(CrazySolError::Overflow)?;
 
-                recruiter_reward = Some(PCRAZY_RECRUITMENT_REWARDS);
-            }
+            recruiter_reward = Some(PCRAZY_RECRUITMENT_REWARDS);
         }
 
         let mut current_recruiter = recruiter_state.recruiter;
@@ -113,9 +162,13 @@ pub fn handle_register_scientist<'info>(
 
         drop(recruiter_data);
 
-        if let Some(specimen_key) = current_recruiter {
-            let (expected_specimen_pda, _) = Pubkey::find_program_address(
-                &[ScientistState::SEED, specimen_key.as_ref()],
+        for level in 1..laboratory_state.research_reward_schedule.len() {
+            let Some(ancestor_key) = current_recruiter else {
+                break;
+            };
+
+            let (expected_ancestor_pda, _) = Pubkey::find_program_address(
+                &[ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), ancestor_key.as_ref()],
                 ctx.program_id
             );
 
@@ -124,68 +177,37 @@ pub fn handle_register_scientist<'info>(
                 CrazySolError::MissingAccount
             );
 
-            let specimen_account = &remaining_accounts[account_index];
+            let ancestor_account = &remaining_accounts[account_index];
             account_index += 1;
 
             require!(
-                specimen_account.key() == expected_specimen_pda,
+                ancestor_account.key() == expected_ancestor_pda,
                 CrazySolError::InvalidRecruiter
             );
 
             {
-                let mut specimen_data = specimen_account.try_borrow_mut_data()?;
-                let mut specimen_state = ScientistState::try_deserialize(&mut specimen_data.as_ref())
+                let mut ancestor_data = ancestor_account.try_borrow_mut_data()?;
+                let mut ancestor_state = ScientistState::try_deserialize(&mut ancestor_data.as_ref())
                     .map_err(|_| CrazySolError::InvalidRecruiter)?;
 
                 require!(
-                    specimen_state.owner == specimen_key,
+                    ancestor_state.owner == ancestor_key,
                     CrazySolError::InvalidRecruiter
                 );
 
-                specimen_state.specimens_count = specimen_state.specimens_count
+                // Only three ancestor counters exist, so every level from 3 down is aggregated into `cultures_count`.
+                let recruits_count = match level {
+                    1 => &mut ancestor_state.specimens_count,
+                    2 => &mut ancestor_state.samples_count,
+                    _ => &mut ancestor_state.cultures_count,
+                };
+                *recruits_count = recruits_count
                     .checked_add(1)
                     .ok_or(CrazySolError::Overflow)?;
 
-                current_recruiter = specimen_state.recruiter;
+                current_recruiter = ancestor_state.recruiter;
 
-                specimen_state.try_serialize(&mut specimen_data.as_mut())?;
-            }
-
-            if let Some(sample_key) = current_recruiter {
-                let (expected_sample_pda, _) = Pubkey::find_program_address(
-                    &[ScientistState::SEED, sample_key.as_ref()],
-                    ctx.program_id
-                );
-
-                require!(
-                    account_index < remaining_accounts.len(),
-                    CrazySolError::MissingAccount
-                );
-
-                let sample_account = &remaining_accounts[account_index];
-
-                require!(
-                    sample_account.key() == expected_sample_pda,
-                    CrazySolError::InvalidRecruiter
-                );
-
-                {
-                    let mut sample_data = sample_account.try_borrow_mut_data()?;
-                    let mut sample_state = ScientistState::try_deserialize(&mut sample_data.as_ref())
-                        .map_err(|_| CrazySolError::InvalidRecruiter)?;
-
-                    require!(
-                        sample_state.owner == sample_key,
-                        CrazySolError::InvalidRecruiter
-                    );
-
-
-                    sample_state.samples_count = sample_state.samples_count
-                        .checked_add(1)
-                        .ok_or(CrazySolError::Overflow)?;
-
-                    sample_state.try_serialize(&mut sample_data.as_mut())?;
-                }
+                ancestor_state.try_serialize(&mut ancestor_data.as_mut())?;
             }
         }
 
@@ -194,12 +216,12 @@ pub fn handle_register_scientist<'info>(
             .ok_or(CrazySolError::Overflow)?;
     }
 
-    if username.len() > ScientistState::MAX_USERNAME_LENGTH {
-        return Err(CrazySolError::UsernameTooLong.into());
-    }
+    let username_record = &mut ctx.accounts.username_record;
+    username_record.username = normalize_username(&username)?;
+    username_record.owner = ctx.accounts.scientist.key();
 
     scientist_state.owner = ctx.accounts.scientist.key();
-    scientist_state.username = username;
+    scientist_state.username = username.trim().to_string();
     scientist_state.recruiter = recruiter;
     scientist_state.owned_pill = 0;
     scientist_state.owned_pcrazy = 0;
@@ -213,8 +235,11 @@ pub fn handle_register_scientist<'info>(
     scientist_state.test_subjects_count = 0;
     scientist_state.specimens_count = 0;
     scientist_state.samples_count = 0;
+    scientist_state.cultures_count = 0;
     scientist_state.earned_sol = 0;
     scientist_state.earned_sol_from_research = 0;
+    scientist_state.yield_debt = 0;
+    scientist_state.curve_cost_basis = 0;
 
     experiment_state.total_scientists = experiment_state.total_scientists
         .checked_add(1)
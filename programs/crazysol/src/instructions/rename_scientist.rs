@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::RenameScientistEvent, normalize_username, require_initialized, require_operational, states::{LaboratoryState, ScientistState, UsernameRecord}, username_seed};
+
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct RenameScientist<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(
+        mut,
+        seeds = [UsernameRecord::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), username_seed(&scientist_state.username).as_ref()],
+        bump,
+        has_one = owner @ CrazySolError::Unauthorized,
+    )]
+    pub current_username_record: Account<'info, UsernameRecord>,
+
+    // Omitted when only the case changes, since the name keeps its record.
+    #[account(
+        init,
+        payer = owner,
+        space = UsernameRecord::SIZE,
+        seeds = [UsernameRecord::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), username_seed(&new_username).as_ref()],
+        bump
+    )]
+    pub new_username_record: Option<Account<'info, UsernameRecord>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_rename_scientist(
+    ctx: Context<RenameScientist>,
+    new_username: String
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+    require_operational(laboratory_state)?;
+
+    let normalized_username = normalize_username(&new_username)?;
+
+    // A change of case keeps the same record; anything else releases the old one.
+    if normalized_username != ctx.accounts.current_username_record.username {
+        let new_username_record = ctx.accounts.new_username_record
+            .as_mut()
+            .ok_or(CrazySolError::MissingAccount)?;
+        new_username_record.username = normalized_username;
+        new_username_record.owner = ctx.accounts.owner.key();
+
+        ctx.accounts.current_username_record.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let old_username = std::mem::replace(&mut scientist_state.username, new_username.trim().to_string());
+
+    emit!(RenameScientistEvent {
+        scientist: scientist_state.owner,
+        old_username,
+        new_username: scientist_state.username.clone(),
+    });
+
+    Ok(())
+}
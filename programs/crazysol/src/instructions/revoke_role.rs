@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::RevokeRoleEvent, require_initialized, require_role_admin_for, states::{LaboratoryState, RoleGrant}};
+
+#[derive(Accounts)]
+#[instruction(grantee: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), grantee.as_ref()],
+        bump
+    )]
+    pub grantee_role_grant: Account<'info, RoleGrant>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_revoke_role(
+    ctx: Context<RevokeRole>,
+    grantee: Pubkey,
+    roles: u8
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let authority = ctx.accounts.authority.key();
+
+    require_initialized(laboratory_state)?;
+    require_role_admin_for(
+        laboratory_state,
+        &authority,
+        ctx.accounts.role_grant.as_ref(),
+        roles
+    )?;
+
+    let grantee_role_grant = &mut ctx.accounts.grantee_role_grant;
+    grantee_role_grant.roles &= !roles;
+    grantee_role_grant.updated_by = authority;
+    grantee_role_grant.updated_at = Clock::get()?.unix_timestamp;
+
+    let remaining_roles = grantee_role_grant.roles;
+
+    emit!(RevokeRoleEvent {
+        grantee,
+        revoked_roles: roles,
+        roles: remaining_roles,
+        revoked_by: authority,
+    });
+
+    if remaining_roles == 0 {
+        grantee_role_grant.close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::SetPauseFlagsEvent, require_initialized, require_role, states::{LaboratoryState, RoleGrant}, validate_pause_flags};
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    #[account(
+        mut,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_pause_flags(
+    ctx: Context<SetPauseFlags>,
+    pause_flags: u8
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::PAUSER
+    )?;
+
+    validate_pause_flags(pause_flags)?;
+
+    let previous_pause_flags = laboratory_state.pause_flags;
+    laboratory_state.pause_flags = pause_flags;
+
+    emit!(SetPauseFlagsEvent {
+        previous_pause_flags,
+        new_pause_flags: pause_flags,
+    });
+
+    Ok(())
+}
@@ -1,19 +1,24 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::CrazySolError, events::{SwitchEmergencyLockdownEvent}, require_initialized, states::LaboratoryState};
+use crate::{events::{SwitchEmergencyLockdownEvent}, require_initialized, require_role, states::{LaboratoryState, RoleGrant}};
 
 #[derive(Accounts)]
 pub struct SwitchEmergencyLockdown<'info> {
     #[account(
         mut,
-        has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(mut)]
-    pub director: Signer<'info>,
+    pub authority: Signer<'info>,
 }
 
 pub fn handle_switch_emergency_lockdown(
@@ -23,6 +28,12 @@ pub fn handle_switch_emergency_lockdown(
     let laboratory_state = &mut ctx.accounts.laboratory_state;
 
     require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::PAUSER
+    )?;
 
     laboratory_state.emergency_lockdown = emergency_lockdown;
 
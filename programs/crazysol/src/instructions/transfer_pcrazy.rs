@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::TransferPcrazyEvent, require_initialized, require_innoculation_not_happened, require_operational, states::{LaboratoryState, ScientistState}};
+
+#[derive(Accounts)]
+pub struct TransferPcrazy<'info> {
+    #[account(
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub scientist_state: Account<'info, ScientistState>,
+
+    #[account(
+        mut,
+        seeds = [ScientistState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), recipient_state.owner.as_ref()],
+        bump,
+    )]
+    pub recipient_state: Account<'info, ScientistState>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_transfer_pcrazy(
+    ctx: Context<TransferPcrazy>,
+    amount: u64
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+    let scientist_state = &mut ctx.accounts.scientist_state;
+    let recipient_state = &mut ctx.accounts.recipient_state;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_operational(laboratory_state)?;
+    // Balances are frozen at innoculation as the CRAZY vesting snapshot.
+    require_innoculation_not_happened(laboratory_state)?;
+
+    let policy = laboratory_state.pcrazy_transfer_policy;
+    require!(
+        policy.enabled,
+        CrazySolError::PcrazyTransfersDisabled
+    );
+    require!(
+        policy.max_transfer_amount == 0 || amount <= policy.max_transfer_amount,
+        CrazySolError::PcrazyTransferTooLarge
+    );
+    require!(
+        recipient_state.owner != scientist_state.owner,
+        CrazySolError::InvalidScientistAddress
+    );
+
+    if amount == 0 || scientist_state.owned_pcrazy < amount {
+        return Err(CrazySolError::InsufficientpCRAZY.into());
+    }
+
+    scientist_state.owned_pcrazy = scientist_state.owned_pcrazy
+        .checked_sub(amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    recipient_state.owned_pcrazy = recipient_state.owned_pcrazy
+        .checked_add(amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(TransferPcrazyEvent {
+        from: scientist_state.owner,
+        to: recipient_state.owner,
+        amount,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -1,41 +1,58 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::CrazySolError, events::UpdateBigPharmaEvent, require_initialized, states::LaboratoryState};
+use crate::{errors::CrazySolError, queue_parameter_change, require_initialized, require_role, states::{LaboratoryState, ParameterChange, ParameterChangeKind, PendingChange, RoleGrant}};
 
 #[derive(Accounts)]
 pub struct UpdateBigPharma<'info> {
     #[account(
-        mut,
-        has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = PendingChange::SIZE,
+        seeds = [PendingChange::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), &[ParameterChangeKind::BigPharma as u8]],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(mut)]
-    pub director: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handle_update_big_pharma(
     ctx: Context<UpdateBigPharma>,
     new_big_pharma: Pubkey
 ) -> Result<()> {
-    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let laboratory_state = &ctx.accounts.laboratory_state;
 
     require_initialized(laboratory_state)?;
-
-    let old_big_pharma = laboratory_state.big_pharma;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::TREASURER
+    )?;
 
     if !new_big_pharma.is_on_curve() {
         return Err(CrazySolError::InvalidPubkey.into());
     }
 
-    laboratory_state.big_pharma = new_big_pharma;
-
-    emit!(UpdateBigPharmaEvent {
-        old_big_pharma,
-        new_big_pharma,
-    });
-
-    Ok(())
+    queue_parameter_change(
+        &mut ctx.accounts.pending_change,
+        laboratory_state,
+        ParameterChange::BigPharma(new_big_pharma),
+        ctx.accounts.authority.key()
+    )
 }
@@ -1,41 +1,56 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::CrazySolError, events::UpdateContainmentTaxBpsEvent, require_initialized, states::LaboratoryState};
+use crate::{queue_parameter_change, require_initialized, require_role, states::{LaboratoryState, ParameterChange, ParameterChangeKind, PendingChange, RoleGrant}, validate_containment_tax_bps};
 
 #[derive(Accounts)]
 pub struct UpdateContainmentTaxBps<'info> {
     #[account(
-        mut,
-        has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = PendingChange::SIZE,
+        seeds = [PendingChange::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), &[ParameterChangeKind::ContainmentTaxBps as u8]],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(mut)]
-    pub director: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handle_update_containment_tax_bps(
     ctx: Context<UpdateContainmentTaxBps>,
     new_containment_tax_bps: u16
 ) -> Result<()> {
-    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let laboratory_state = &ctx.accounts.laboratory_state;
 
     require_initialized(laboratory_state)?;
-
-    let previous_containment_tax_bps = laboratory_state.containment_tax_bps;
-
-    if new_containment_tax_bps > 100 {
-        return Err(CrazySolError::InvalidFeePercentage.into());
-    }
-
-    laboratory_state.containment_tax_bps = new_containment_tax_bps;
-
-    emit!(UpdateContainmentTaxBpsEvent {
-        previous_containment_tax_bps,
-        new_containment_tax_bps: laboratory_state.containment_tax_bps,
-    });
-
-    Ok(())
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::FORMULA_MANAGER
+    )?;
+
+    validate_containment_tax_bps(new_containment_tax_bps, laboratory_state.max_containment_tax_bps)?;
+
+    queue_parameter_change(
+        &mut ctx.accounts.pending_change,
+        laboratory_state,
+        ParameterChange::ContainmentTaxBps(new_containment_tax_bps),
+        ctx.accounts.authority.key()
+    )
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::UpdateCouncilEvent, states::Council, validate_council_members};
+
+#[derive(Accounts)]
+pub struct UpdateCouncil<'info> {
+    #[account(
+        mut,
+        seeds = [Council::SEED, council.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        seeds = [Council::AUTHORITY_SEED, council.key().as_ref()],
+        bump = council.authority_bump
+    )]
+    pub council_authority: Signer<'info>,
+}
+
+pub fn handle_update_council(
+    ctx: Context<UpdateCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8
+) -> Result<()> {
+    let council = &mut ctx.accounts.council;
+
+    validate_council_members(&members, threshold)?;
+
+    council.members = members;
+    council.threshold = threshold;
+    council.version = council.version
+        .checked_add(1)
+        .ok_or(CrazySolError::Overflow)?;
+
+    emit!(UpdateCouncilEvent {
+        council: council.key(),
+        members: council.members.clone(),
+        threshold,
+        version: council.version,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::UpdateFormulaBoundsEvent, require_initialized, states::{FormulaBounds, LaboratoryState}};
+
+#[derive(Accounts)]
+pub struct UpdateFormulaBounds<'info> {
+    #[account(
+        mut,
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+}
+
+pub fn handle_update_formula_bounds(
+    ctx: Context<UpdateFormulaBounds>,
+    new_formula_bounds: FormulaBounds
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+
+    new_formula_bounds.validate()?;
+
+    let previous_formula_bounds = laboratory_state.formula_bounds;
+    laboratory_state.formula_bounds = new_formula_bounds;
+
+    emit!(UpdateFormulaBoundsEvent {
+        previous_formula_bounds,
+        new_formula_bounds,
+    });
+
+    Ok(())
+}
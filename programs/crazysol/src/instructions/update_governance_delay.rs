@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, queue_parameter_change, require_initialized, states::{LaboratoryState, ParameterChange, ParameterChangeKind, PendingChange}, validate_governance_delay};
+
+#[derive(Accounts)]
+pub struct UpdateGovernanceDelay<'info> {
+    #[account(
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        init,
+        payer = director,
+        space = PendingChange::SIZE,
+        seeds = [PendingChange::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), &[ParameterChangeKind::GovernanceDelay as u8]],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_governance_delay(
+    ctx: Context<UpdateGovernanceDelay>,
+    new_governance_delay: i64
+) -> Result<()> {
+    let laboratory_state = &ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+
+    validate_governance_delay(new_governance_delay)?;
+
+    queue_parameter_change(
+        &mut ctx.accounts.pending_change,
+        laboratory_state,
+        ParameterChange::GovernanceDelay(new_governance_delay),
+        ctx.accounts.director.key()
+    )
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::UpdatePcrazyTransferPolicyEvent, require_initialized, states::{LaboratoryState, PcrazyTransferPolicy}};
+
+#[derive(Accounts)]
+pub struct UpdatePcrazyTransferPolicy<'info> {
+    #[account(
+        mut,
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+}
+
+pub fn handle_update_pcrazy_transfer_policy(
+    ctx: Context<UpdatePcrazyTransferPolicy>,
+    new_pcrazy_transfer_policy: PcrazyTransferPolicy
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+
+    let previous_pcrazy_transfer_policy = laboratory_state.pcrazy_transfer_policy;
+    laboratory_state.pcrazy_transfer_policy = new_pcrazy_transfer_policy;
+
+    emit!(UpdatePcrazyTransferPolicyEvent {
+        previous_pcrazy_transfer_policy,
+        new_pcrazy_transfer_policy,
+    });
+
+    Ok(())
+}
@@ -1,34 +1,65 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::CrazySolError, events::UpdateReactionFormulaEvent, require_initialized, states::{LaboratoryState, ReactionFormula}};
+use crate::{queue_parameter_change, require_initialized, require_role, states::{LaboratoryState, ParameterChange, ParameterChangeKind, PendingChange, ReactionFormula, ReactorState, RoleGrant}};
 
 #[derive(Accounts)]
 pub struct UpdateReactionFormula<'info> {
     #[account(
-        mut,
-        has_one = director @ CrazySolError::Unauthorized,
-        seeds = [LaboratoryState::SEED],
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
         bump
     )]
     pub laboratory_state: Account<'info, LaboratoryState>,
 
+    #[account(
+        seeds = [ReactorState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reactor_state: Account<'info, ReactorState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingChange::SIZE,
+        seeds = [PendingChange::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), &[ParameterChangeKind::ReactionFormula as u8]],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(mut)]
-    pub director: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handle_update_reaction_formula(
     ctx: Context<UpdateReactionFormula>,
     new_reaction_formula: ReactionFormula,
 ) -> Result<()> {
-    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let laboratory_state = &ctx.accounts.laboratory_state;
 
     require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::FORMULA_MANAGER
+    )?;
 
-    laboratory_state.reaction_formula = new_reaction_formula;
-
-    emit!(UpdateReactionFormulaEvent {
-        new_reaction_formula,
-    });
+    laboratory_state.formula_bounds.check_reaction_formula(
+        &new_reaction_formula,
+        Some((&laboratory_state.reaction_formula, ctx.accounts.reactor_state.pill_supply))
+    )?;
 
-    Ok(())
+    queue_parameter_change(
+        &mut ctx.accounts.pending_change,
+        laboratory_state,
+        ParameterChange::ReactionFormula(new_reaction_formula),
+        ctx.accounts.authority.key()
+    )
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::UpdateResearchRewardScheduleEvent, require_initialized, require_role, states::{LaboratoryState, RoleGrant}, validate_research_reward_schedule};
+
+#[derive(Accounts)]
+pub struct UpdateResearchRewardSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(
+        seeds = [RoleGrant::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_update_research_reward_schedule(
+    ctx: Context<UpdateResearchRewardSchedule>,
+    new_research_reward_schedule: Vec<u16>
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+
+    require_initialized(laboratory_state)?;
+    require_role(
+        laboratory_state,
+        ctx.accounts.authority.key,
+        ctx.accounts.role_grant.as_ref(),
+        RoleGrant::FORMULA_MANAGER
+    )?;
+
+    validate_research_reward_schedule(&new_research_reward_schedule)?;
+
+    let previous_research_reward_schedule = std::mem::replace(
+        &mut laboratory_state.research_reward_schedule,
+        new_research_reward_schedule
+    );
+
+    emit!(UpdateResearchRewardScheduleEvent {
+        previous_research_reward_schedule,
+        new_research_reward_schedule: laboratory_state.research_reward_schedule.clone(),
+    });
+
+    Ok(())
+}
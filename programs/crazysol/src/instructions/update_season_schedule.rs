@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, events::UpdateSeasonScheduleEvent, require_initialized, require_season_not_finalized, states::{LaboratoryState, SeasonSchedule}};
+
+#[derive(Accounts)]
+pub struct UpdateSeasonSchedule<'info> {
+    #[account(
+        mut,
+        has_one = director @ CrazySolError::Unauthorized,
+        seeds = [LaboratoryState::SEED, laboratory_state.laboratory_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub laboratory_state: Account<'info, LaboratoryState>,
+
+    #[account(mut)]
+    pub director: Signer<'info>,
+}
+
+pub fn handle_update_season_schedule(
+    ctx: Context<UpdateSeasonSchedule>,
+    new_season_schedule: SeasonSchedule
+) -> Result<()> {
+    let laboratory_state = &mut ctx.accounts.laboratory_state;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_initialized(laboratory_state)?;
+    require_season_not_finalized(laboratory_state)?;
+
+    new_season_schedule.validate()?;
+
+    // Phases that have already begun cannot be moved. Once settlement is
+    // reachable nothing can, so finalize_season cannot be pushed back.
+    let previous_season_schedule = laboratory_state.season_schedule;
+    require!(
+        current_ts < previous_season_schedule.settlement_ts,
+        CrazySolError::InvalidSeasonSchedule
+    );
+    if current_ts >= previous_season_schedule.start_ts {
+        require!(
+            new_season_schedule.start_ts == previous_season_schedule.start_ts,
+            CrazySolError::InvalidSeasonSchedule
+        );
+    }
+    if current_ts >= previous_season_schedule.injection_end_ts {
+        require!(
+            new_season_schedule.injection_end_ts == previous_season_schedule.injection_end_ts,
+            CrazySolError::InvalidSeasonSchedule
+        );
+    } else {
+        require!(
+            new_season_schedule.injection_end_ts > current_ts,
+            CrazySolError::InvalidSeasonSchedule
+        );
+    }
+    require!(
+        new_season_schedule.settlement_ts > current_ts,
+        CrazySolError::InvalidSeasonSchedule
+    );
+
+    laboratory_state.season_schedule = new_season_schedule;
+
+    emit!(UpdateSeasonScheduleEvent {
+        previous_season_schedule,
+        new_season_schedule,
+    });
+
+    Ok(())
+}
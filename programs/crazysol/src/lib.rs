@@ -52,17 +52,19 @@ pub mod crazysol {
 
     pub fn initialize(
         ctx: Context<Initialize>,
+        laboratory_id: u64,
         big_pharma: Pubkey,
-        containment_tax_bps: u16,
         owned_pcrazy: u64,
-        external_reactor: Pubkey
+        external_reactor: Pubkey,
+        config: LaboratoryConfig
     ) -> Result<()> {
         handle_initialize(
             ctx,
+            laboratory_id,
             big_pharma,
-            containment_tax_bps,
             owned_pcrazy,
-            external_reactor
+            external_reactor,
+            config
         )
     }
 
@@ -76,16 +78,134 @@ pub mod crazysol {
         )
     }
 
-    pub fn replace_director(
-        ctx: Context<ReplaceDirector>,
+    pub fn set_pause_flags(
+        ctx: Context<SetPauseFlags>,
+        pause_flags: u8
+    ) -> Result<()> {
+        handle_set_pause_flags(
+            ctx,
+            pause_flags
+        )
+    }
+
+    pub fn enable_emergency_exit(
+        ctx: Context<EnableEmergencyExit>,
+    ) -> Result<()> {
+        handle_enable_emergency_exit(
+            ctx
+        )
+    }
+
+    pub fn create_council(
+        ctx: Context<CreateCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        handle_create_council(
+            ctx,
+            members,
+            threshold
+        )
+    }
+
+    pub fn update_council(
+        ctx: Context<UpdateCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        handle_update_council(
+            ctx,
+            members,
+            threshold
+        )
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        instruction_data: Vec<u8>,
+        accounts: Vec<ProposalAccountMeta>
+    ) -> Result<()> {
+        handle_create_proposal(
+            ctx,
+            instruction_data,
+            accounts
+        )
+    }
+
+    pub fn approve_proposal(
+        ctx: Context<ApproveProposal>
+    ) -> Result<()> {
+        handle_approve_proposal(
+            ctx
+        )
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>
+    ) -> Result<()> {
+        handle_execute_proposal(
+            ctx
+        )
+    }
+
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        grantee: Pubkey,
+        roles: u8
+    ) -> Result<()> {
+        handle_grant_role(
+            ctx,
+            grantee,
+            roles
+        )
+    }
+
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        grantee: Pubkey,
+        roles: u8
+    ) -> Result<()> {
+        handle_revoke_role(
+            ctx,
+            grantee,
+            roles
+        )
+    }
+
+    pub fn propose_director(
+        ctx: Context<ProposeDirector>,
         new_director: Pubkey
     ) -> Result<()> {
-        handle_replace_director(
+        handle_propose_director(
             ctx,
             new_director
         )
     }
 
+    pub fn accept_director(
+        ctx: Context<AcceptDirector>
+    ) -> Result<()> {
+        handle_accept_director(
+            ctx
+        )
+    }
+
+    pub fn cancel_director_proposal(
+        ctx: Context<CancelDirectorProposal>
+    ) -> Result<()> {
+        handle_cancel_director_proposal(
+            ctx
+        )
+    }
+
+    pub fn reconcile_reserves(
+        ctx: Context<ReconcileReserves>
+    ) -> Result<()> {
+        handle_reconcile_reserves(
+            ctx
+        )
+    }
+
     pub fn update_containment_tax_bps(
         ctx: Context<UpdateContainmentTaxBps>,
         new_containment_tax_bps: u16
@@ -116,6 +236,84 @@ pub mod crazysol {
         )
     }
 
+    pub fn update_formula_bounds(
+        ctx: Context<UpdateFormulaBounds>,
+        new_formula_bounds: FormulaBounds,
+    ) -> Result<()> {
+        handle_update_formula_bounds(
+            ctx,
+            new_formula_bounds
+        )
+    }
+
+    pub fn update_governance_delay(
+        ctx: Context<UpdateGovernanceDelay>,
+        new_governance_delay: i64,
+    ) -> Result<()> {
+        handle_update_governance_delay(
+            ctx,
+            new_governance_delay
+        )
+    }
+
+    pub fn execute_pending_change(
+        ctx: Context<ExecutePendingChange>,
+        kind: ParameterChangeKind,
+    ) -> Result<()> {
+        handle_execute_pending_change(
+            ctx,
+            kind
+        )
+    }
+
+    pub fn cancel_pending_change(
+        ctx: Context<CancelPendingChange>,
+        kind: ParameterChangeKind,
+    ) -> Result<()> {
+        handle_cancel_pending_change(
+            ctx,
+            kind
+        )
+    }
+
+    pub fn update_research_reward_schedule(
+        ctx: Context<UpdateResearchRewardSchedule>,
+        new_research_reward_schedule: Vec<u16>,
+    ) -> Result<()> {
+        handle_update_research_reward_schedule(
+            ctx,
+            new_research_reward_schedule
+        )
+    }
+
+    pub fn update_pcrazy_transfer_policy(
+        ctx: Context<UpdatePcrazyTransferPolicy>,
+        new_pcrazy_transfer_policy: PcrazyTransferPolicy,
+    ) -> Result<()> {
+        handle_update_pcrazy_transfer_policy(
+            ctx,
+            new_pcrazy_transfer_policy
+        )
+    }
+
+    pub fn update_season_schedule(
+        ctx: Context<UpdateSeasonSchedule>,
+        new_season_schedule: SeasonSchedule,
+    ) -> Result<()> {
+        handle_update_season_schedule(
+            ctx,
+            new_season_schedule
+        )
+    }
+
+    pub fn finalize_season(
+        ctx: Context<FinalizeSeason>,
+    ) -> Result<()> {
+        handle_finalize_season(
+            ctx
+        )
+    }
+
     pub fn give_public_funding(
         ctx: Context<GivePublicFunding>,
         scientist: Pubkey,
@@ -128,6 +326,40 @@ pub mod crazysol {
         )
     }
 
+    pub fn give_public_funding_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, GivePublicFundingBatch<'info>>,
+        entries: Vec<PublicFundingEntry>
+    ) -> Result<()> {
+        handle_give_public_funding_batch(
+            ctx,
+            entries
+        )
+    }
+
+    pub fn create_funding_campaign(
+        ctx: Context<CreateFundingCampaign>,
+        campaign_id: u64,
+        merkle_root: [u8; 32],
+        budget: u64,
+        max_claims: u32
+    ) -> Result<()> {
+        handle_create_funding_campaign(
+            ctx,
+            campaign_id,
+            merkle_root,
+            budget,
+            max_claims
+        )
+    }
+
+    pub fn close_funding_campaign(
+        ctx: Context<CloseFundingCampaign>,
+    ) -> Result<()> {
+        handle_close_funding_campaign(
+            ctx
+        )
+    }
+
     // ---------------
     // User functions
     // ---------------
@@ -135,10 +367,14 @@ pub mod crazysol {
     pub fn inject<'info>(
         ctx: Context<'_, '_, '_, 'info, Inject<'info>>,
         deposit: u64,
+        min_pills_out: u128,
+        max_price_per_pill: Option<u64>,
     ) -> Result<()> {
             handle_inject(
                 ctx,
-                deposit
+                deposit,
+                min_pills_out,
+                max_price_per_pill
             )
         }
 
@@ -150,11 +386,27 @@ pub mod crazysol {
         )
     }
 
+    pub fn dissolve(
+        ctx: Context<Dissolve>,
+        pill_amount: u128,
+        min_lamports_out: u64
+    ) -> Result<()> {
+        handle_dissolve(
+            ctx,
+            pill_amount,
+            min_lamports_out
+        )
+    }
+
     pub fn mutate(
         ctx: Context<Mutate>,
+        min_pills_out: u128,
+        max_price_per_pill: Option<u64>,
     ) -> Result<()> {
         handle_mutate(
-            ctx
+            ctx,
+            min_pills_out,
+            max_price_per_pill
         )
     }
 
@@ -170,11 +422,81 @@ pub mod crazysol {
         ctx: Context<'_, '_, '_, 'info, RegisterScientist<'info>>,
         username: String,
         recruiter: Option<Pubkey>,
+        referral_code: Option<String>,
     ) -> Result<()> {
         handle_register_scientist(
             ctx,
             username,
-            recruiter
+            recruiter,
+            referral_code
+        )
+    }
+
+    pub fn rename_scientist(
+        ctx: Context<RenameScientist>,
+        new_username: String,
+    ) -> Result<()> {
+        handle_rename_scientist(
+            ctx,
+            new_username
+        )
+    }
+
+    pub fn claim_referral_code(
+        ctx: Context<ClaimReferralCode>,
+        code: String,
+    ) -> Result<()> {
+        handle_claim_referral_code(
+            ctx,
+            code
+        )
+    }
+
+    pub fn claim_public_funding(
+        ctx: Context<ClaimPublicFunding>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>
+    ) -> Result<()> {
+        handle_claim_public_funding(
+            ctx,
+            index,
+            amount,
+            proof
+        )
+    }
+
+    pub fn transfer_pcrazy(
+        ctx: Context<TransferPcrazy>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_transfer_pcrazy(
+            ctx,
+            amount
+        )
+    }
+
+    pub fn claim_crazy(
+        ctx: Context<ClaimCrazy>,
+    ) -> Result<()> {
+        handle_claim_crazy(
+            ctx
+        )
+    }
+
+    pub fn emergency_withdraw(
+        ctx: Context<EmergencyWithdraw>,
+    ) -> Result<()> {
+        handle_emergency_withdraw(
+            ctx
+        )
+    }
+
+    pub fn claim_settlement(
+        ctx: Context<ClaimSettlement>,
+    ) -> Result<()> {
+        handle_claim_settlement(
+            ctx
         )
     }
 
@@ -192,9 +514,17 @@ pub mod crazysol {
 
     pub fn get_pill_potency(
         ctx: Context<GetPillPotency>,
-    ) -> Result<u64> {
+    ) -> Result<u128> {
         handle_get_pill_potency(
             ctx
         )
     }
+
+    pub fn get_solvency_report(
+        ctx: Context<GetSolvencyReport>,
+    ) -> Result<SolvencyReport> {
+        handle_get_solvency_report(
+            ctx
+        )
+    }
 }
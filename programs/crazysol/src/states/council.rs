@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Council {
+    pub laboratory_id: u64,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub version: u32,
+    pub proposal_count: u64,
+    pub authority_bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl Council {
+    pub const MAX_MEMBERS: usize = 10;
+    pub const SIZE: usize =
+        8 + // discriminator
+        8 + // laboratory_id
+        4 + 32 * Self::MAX_MEMBERS + // members
+        1 + // threshold
+        4 + // version
+        8 + // proposal_count
+        1 + // authority_bump
+        32; // reserved
+    pub const SEED: &[u8] = b"council";
+    // System-owned PDA that signs executed proposals and can be set as director.
+    pub const AUTHORITY_SEED: &[u8] = b"council-authority";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[account]
+pub struct Proposal {
+    pub council: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub council_version: u32,
+    pub instruction_data: Vec<u8>,
+    pub accounts: Vec<ProposalAccountMeta>,
+    pub approvals: u16,
+    pub executed: bool,
+    pub created_at: i64,
+    pub reserved: [u8; 32],
+}
+
+impl Proposal {
+    pub const MAX_INSTRUCTION_DATA_LENGTH: usize = 512;
+    pub const MAX_ACCOUNTS: usize = 12;
+    pub const SIZE: usize =
+        8 + // discriminator
+        32 + // council
+        8 + // id
+        32 + // proposer
+        4 + // council_version
+        4 + Self::MAX_INSTRUCTION_DATA_LENGTH + // instruction_data
+        4 + ProposalAccountMeta::INIT_SPACE * Self::MAX_ACCOUNTS + // accounts
+        2 + // approvals
+        1 + // executed
+        8 + // created_at
+        32; // reserved
+    pub const SEED: &[u8] = b"proposal";
+}
@@ -10,7 +10,9 @@ pub struct ExperimentState {
     pub total_sol_injected: u64,
     pub total_scientists: u64,
     pub total_scientists_recruited: u64,
-    pub reserved: [u8; 128],
+    pub total_pills_dissolved: u128,
+    pub total_sol_dissolved: u64,
+    pub reserved: [u8; 104],
 }
 
 impl ExperimentState {
@@ -24,6 +26,8 @@ impl ExperimentState {
         8 + // total_sol_injected
         8 + // total_scientists
         8 + // total_scientists_recruited
-        128; // reserved
+        16 + // total_pills_dissolved
+        8 + // total_sol_dissolved
+        104; // reserved
     pub const SEED: &[u8] = b"experiment-state";
 }
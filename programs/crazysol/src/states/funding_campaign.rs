@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct FundingCampaign {
+    pub campaign_id: u64,
+    pub merkle_root: [u8; 32],
+    pub budget: u64,
+    pub claimed_amount: u64,
+    pub max_claims: u32,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub reserved: [u8; 32],
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl FundingCampaign {
+    // Keeps the account within the 10 KiB limit for accounts created through CPI.
+    pub const MAX_CLAIMS: u32 = 65_536;
+    pub const BASE_SIZE: usize =
+        8 + // discriminator
+        8 + // campaign_id
+        32 + // merkle_root
+        8 + // budget
+        8 + // claimed_amount
+        4 + // max_claims
+        32 + // created_by
+        8 + // created_at
+        32 + // reserved
+        4; // claimed_bitmap length
+    pub const SEED: &[u8] = b"funding-campaign";
+
+    pub fn bitmap_len(max_claims: u32) -> usize {
+        (max_claims as usize).div_ceil(8)
+    }
+
+    pub fn size(max_claims: u32) -> usize {
+        Self::BASE_SIZE + Self::bitmap_len(max_claims)
+    }
+}
@@ -1,15 +1,88 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MAX_CURVE_BREAKPOINTS, MAX_RESEARCH_REWARD_LEVELS};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace, Copy)]
+pub struct CurveBreakpoint {
+    pub supply: u128,
+    pub potency: u128,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy)]
+pub enum CurveKind {
+    Linear {
+        p0: u128,
+        m: u128,
+    },
+    Exponential {
+        p0: u128,
+        growth_rate: u64,
+    },
+    Sigmoid {
+        cap: u128,
+        midpoint: u128,
+        steepness: u64,
+    },
+    PiecewiseLinear {
+        breakpoints: [CurveBreakpoint; MAX_CURVE_BREAKPOINTS],
+        breakpoint_count: u8,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy)]
 pub struct ReactionFormula {
-    pub bonding_curve_m: u8,
-    pub bonding_curve_p0: u32,
-    pub reward_rate_per_pill: u64,
-    pub min_daily_yield_bps: u64,
+    pub bonding_curve: CurveKind,
     pub first_injection_bonus: u128,
     pub first_mutation_bonus: u128,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy)]
+pub struct FormulaBounds {
+    pub min_initial_potency: u128,
+    pub max_initial_potency: u128,
+    pub min_first_injection_bonus: u128,
+    pub max_first_injection_bonus: u128,
+    pub min_first_mutation_bonus: u128,
+    pub max_first_mutation_bonus: u128,
+    pub max_potency_change_bps: u16,
+    pub max_bonus_change_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy)]
+pub struct SeasonSchedule {
+    pub start_ts: i64,
+    pub injection_end_ts: i64,
+    pub settlement_ts: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy)]
+pub struct VestingSchedule {
+    pub cliff_seconds: i64,
+    pub duration_seconds: i64,
+    pub tge_unlock_bps: u16,
+}
+
+// max_transfer_amount of 0 means uncapped.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy, Default)]
+pub struct PcrazyTransferPolicy {
+    pub enabled: bool,
+    pub max_transfer_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LaboratoryConfig {
+    pub reaction_formula: ReactionFormula,
+    pub formula_bounds: FormulaBounds,
+    pub containment_tax_bps: u16,
+    pub max_containment_tax_bps: u16,
+    pub research_reward_schedule: Vec<u16>,
+    pub min_injection_lamports: u64,
+    pub governance_delay: i64,
+    pub season_schedule: SeasonSchedule,
+    pub vesting_schedule: VestingSchedule,
+}
+
 #[account]
 pub struct LaboratoryState {
     pub director: Pubkey,
@@ -18,11 +91,36 @@ pub struct LaboratoryState {
     pub is_initialized: bool,
     pub emergency_lockdown: bool,
     pub innoculation_happened: bool,
-    pub reserved: [u8; 128],
+    pub pending_director: Option<Pubkey>,
+    pub governance_delay: i64,
+    pub max_containment_tax_bps: u16,
+    pub min_injection_lamports: u64,
+    pub laboratory_id: u64,
+    pub season_schedule: SeasonSchedule,
+    pub season_finalized: bool,
+    pub pause_flags: u8,
+    pub emergency_exit: bool,
+    pub crazy_mint: Pubkey,
+    pub innoculated_at: i64,
+    pub reserved: [u8; 2],
     pub reaction_formula: ReactionFormula,
+    pub formula_bounds: FormulaBounds,
+    pub vesting_schedule: VestingSchedule,
+    pub pcrazy_transfer_policy: PcrazyTransferPolicy,
+    pub research_reward_schedule: Vec<u16>,
 }
 
 impl LaboratoryState {
+    pub const PAUSE_DEPOSITS: u8 = 1 << 0;
+    pub const PAUSE_WITHDRAWALS: u8 = 1 << 1;
+    pub const PAUSE_COMPOUNDING: u8 = 1 << 2;
+    pub const PAUSE_STREAKS: u8 = 1 << 3;
+    pub const PAUSE_REGISTRATIONS: u8 = 1 << 4;
+    pub const PAUSE_REFERRAL_PAYOUTS: u8 = 1 << 5;
+    pub const ALL_PAUSE_FLAGS: u8 =
+        Self::PAUSE_DEPOSITS | Self::PAUSE_WITHDRAWALS | Self::PAUSE_COMPOUNDING |
+        Self::PAUSE_STREAKS | Self::PAUSE_REGISTRATIONS | Self::PAUSE_REFERRAL_PAYOUTS;
+
     pub const SIZE: usize =
         8 + // discriminator
         32 + // director
@@ -31,7 +129,22 @@ impl LaboratoryState {
         1 + // is_initialized
         1 + // is_paused
         1 + // tge_happened
-        128 + // reserved
-        ReactionFormula::INIT_SPACE;
+        33 + // pending_director
+        8 + // governance_delay
+        2 + // max_containment_tax_bps
+        8 + // min_injection_lamports
+        8 + // laboratory_id
+        SeasonSchedule::INIT_SPACE + // season_schedule
+        1 + // season_finalized
+        1 + // pause_flags
+        1 + // emergency_exit
+        32 + // crazy_mint
+        8 + // innoculated_at
+        2 + // reserved
+        ReactionFormula::INIT_SPACE +
+        FormulaBounds::INIT_SPACE +
+        VestingSchedule::INIT_SPACE +
+        PcrazyTransferPolicy::INIT_SPACE +
+        4 + 2 * MAX_RESEARCH_REWARD_LEVELS; // research_reward_schedule
     pub const SEED: &[u8] = b"laboratory-state";
 }
@@ -2,8 +2,22 @@ pub mod laboratory_state;
 pub mod scientist_state;
 pub mod reactor_state;
 pub mod experiment_state;
+pub mod council;
+pub mod funding_campaign;
+pub mod pending_change;
+pub mod referral_code;
+pub mod role_grant;
+pub mod username_record;
+pub mod vesting_state;
 
 pub use laboratory_state::*;
 pub use reactor_state::*;
 pub use scientist_state::*;
 pub use experiment_state::*;
+pub use council::*;
+pub use funding_campaign::*;
+pub use pending_change::*;
+pub use referral_code::*;
+pub use role_grant::*;
+pub use username_record::*;
+pub use vesting_state::*;
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::states::ReactionFormula;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ParameterChangeKind {
+    ReactionFormula,
+    ContainmentTaxBps,
+    BigPharma,
+    GovernanceDelay,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Copy)]
+pub enum ParameterChange {
+    ReactionFormula(ReactionFormula),
+    ContainmentTaxBps(u16),
+    BigPharma(Pubkey),
+    GovernanceDelay(i64),
+}
+
+#[account]
+pub struct PendingChange {
+    pub change: ParameterChange,
+    pub proposer: Pubkey,
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub reserved: [u8; 32],
+}
+
+impl PendingChange {
+    pub const SIZE: usize =
+        8 + // discriminator
+        ParameterChange::INIT_SPACE + // change
+        32 + // proposer
+        8 + // queued_at
+        8 + // executable_at
+        32; // reserved
+    pub const SEED: &[u8] = b"pending-change";
+}
@@ -6,7 +6,13 @@ pub struct ReactorState {
     pub owned_pcrazy: u64,
     pub pill_supply: u128,
     pub external_reactor: Pubkey,
-    pub reserved: [u8; 128],
+    pub acc_yield_per_pill: u128,
+    pub undistributed_yield: u64,
+    pub outstanding_yield: u64,
+    pub total_lamports_in: u64,
+    pub total_lamports_out: u64,
+    pub final_lamports_per_pill: u128,
+    pub reserved: [u8; 64],
 }
 
 impl ReactorState {
@@ -16,6 +22,12 @@ impl ReactorState {
         8 + // owned_pre_tge_crazy
         16 + // pill_supply
         32 + // external_reactor
-        128; // reserved
+        16 + // acc_yield_per_pill
+        8 + // undistributed_yield
+        8 + // outstanding_yield
+        8 + // total_lamports_in
+        8 + // total_lamports_out
+        16 + // final_lamports_per_pill
+        64; // reserved
     pub const SEED: &[u8] = b"reactor-state";
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReferralCode {
+    pub code: String,
+    pub owner: Pubkey,
+    pub created_at: i64,
+    pub reserved: [u8; 32],
+}
+
+impl ReferralCode {
+    pub const MIN_CODE_LENGTH: usize = 3;
+    pub const MAX_CODE_LENGTH: usize = 32;
+    pub const SIZE: usize =
+        8 + // discriminator
+        4 + Self::MAX_CODE_LENGTH + // code
+        32 + // owner
+        8 + // created_at
+        32; // reserved
+    pub const SEED: &[u8] = b"referral-code";
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct RoleGrant {
+    pub grantee: Pubkey,
+    pub roles: u8,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub reserved: [u8; 32],
+}
+
+impl RoleGrant {
+    pub const PAUSER: u8 = 1 << 0;
+    pub const TREASURER: u8 = 1 << 1;
+    pub const FORMULA_MANAGER: u8 = 1 << 2;
+    pub const DISTRIBUTOR: u8 = 1 << 3;
+    pub const ROLE_ADMIN: u8 = 1 << 4;
+    pub const ALL_ROLES: u8 =
+        Self::PAUSER | Self::TREASURER | Self::FORMULA_MANAGER | Self::DISTRIBUTOR | Self::ROLE_ADMIN;
+
+    pub const SIZE: usize =
+        8 + // discriminator
+        32 + // grantee
+        1 + // roles
+        32 + // updated_by
+        8 + // updated_at
+        32; // reserved
+    pub const SEED: &[u8] = b"role-grant";
+}
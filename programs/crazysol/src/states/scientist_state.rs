@@ -19,7 +19,10 @@ pub struct ScientistState {
     pub samples_count: u32,
     pub earned_sol: u64,
     pub earned_sol_from_research: u64,
-    pub reserved: [u8; 128],
+    pub yield_debt: u128,
+    pub cultures_count: u32,
+    pub curve_cost_basis: u64,
+    pub reserved: [u8; 100],
 }
 
 impl ScientistState {
@@ -43,6 +46,9 @@ impl ScientistState {
         4 + // samples_count
         8 + // earned_sol
         8 + // earned_sol_from_research
-        128; // reserved
+        16 + // yield_debt
+        4 + // cultures_count
+        8 + // curve_cost_basis
+        100; // reserved
     pub const SEED: &[u8] = b"scientist-state";
 }
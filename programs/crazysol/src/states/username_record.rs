@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::states::ScientistState;
+
+#[account]
+pub struct UsernameRecord {
+    pub username: String,
+    pub owner: Pubkey,
+    pub reserved: [u8; 32],
+}
+
+impl UsernameRecord {
+    pub const MIN_USERNAME_LENGTH: usize = 3;
+    pub const SIZE: usize =
+        8 + // discriminator
+        4 + ScientistState::MAX_USERNAME_LENGTH + // username
+        32 + // owner
+        32; // reserved
+    pub const SEED: &[u8] = b"username-record";
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct VestingState {
+    pub owner: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub reserved: [u8; 32],
+}
+
+impl VestingState {
+    pub const SIZE: usize =
+        8 + // discriminator
+        32 + // owner
+        8 + // total_amount
+        8 + // claimed_amount
+        32; // reserved
+    pub const SEED: &[u8] = b"vesting-state";
+}
@@ -1,34 +1,458 @@
-use uint::construct_uint;
+use anchor_lang::prelude::*;
 
-construct_uint! {
-    pub struct U256(4);
+use crate::{constants::MAX_CURVE_BREAKPOINTS, errors::CrazySolError, states::{CurveBreakpoint, CurveKind}};
+
+use super::fixed_point::{checked_product, div_rounding, exp_fixed, exp_neg_fixed, ln_fixed, mul_div, scale, sqrt_u256, to_u128, Rounding, MAX_EXP_INPUT, U256};
+
+// The starting cost is rounded up and the resulting supply down, so any
+// rounding dust stays in the Reactor.
+pub fn compute_rate_of_centrifugation(supply: u128, deposit: u128, curve: &CurveKind) -> Result<u128> {
+    let start_cost = curve.cumulative_cost(U256::from(supply), Rounding::Up)?;
+    let end_cost = start_cost
+        .checked_add(checked_product(U256::from(deposit), scale())?)
+        .ok_or(CrazySolError::Overflow)?;
+    let end_supply = curve.supply_for_cumulative_cost(end_cost)?;
+
+    to_u128(end_supply.saturating_sub(U256::from(supply)))
+}
+
+// The upper bound of the burned range is rounded down and the lower bound up,
+// so the payout never exceeds the exact area under the curve.
+pub fn compute_rate_of_dissolution(supply: u128, pills: u128, curve: &CurveKind) -> Result<u128> {
+    let remaining_supply = supply
+        .checked_sub(pills)
+        .ok_or(CrazySolError::InsufficientPills)?;
+
+    let end_cost = curve.cumulative_cost(U256::from(supply), Rounding::Down)?;
+    let start_cost = curve.cumulative_cost(U256::from(remaining_supply), Rounding::Up)?;
+
+    to_u128(end_cost.saturating_sub(start_cost) / scale())
+}
+
+pub fn compute_pill_potency(supply: u128, curve: &CurveKind) -> Result<u128> {
+    to_u128(curve.potency_at(U256::from(supply))?)
+}
+
+impl CurveKind {
+    pub fn validate(&self) -> Result<()> {
+        let is_valid = match *self {
+            CurveKind::Linear { m, .. } => m > 0,
+            CurveKind::Exponential { p0, growth_rate } => p0 > 0 && growth_rate > 0,
+            CurveKind::Sigmoid { cap, steepness, .. } => cap > 0 && steepness > 0,
+            CurveKind::PiecewiseLinear { breakpoints, breakpoint_count } => {
+                let count = breakpoint_count as usize;
+
+                (2..=MAX_CURVE_BREAKPOINTS).contains(&count)
+                    && breakpoints[0].supply == 0
+                    && breakpoints[0].potency > 0
+                    && breakpoints[..count]
+                        .windows(2)
+                        .all(|pair| pair[0].supply < pair[1].supply && pair[0].potency <= pair[1].potency)
+            }
+        };
+
+        require!(is_valid, CrazySolError::InvalidBondingCurve);
+        Ok(())
+    }
+
+    // Fixed-point lamports per Pill at the given supply, rounded down.
+    pub fn potency_at(&self, supply: U256) -> Result<U256> {
+        match *self {
+            CurveKind::Linear { p0, m } => {
+                let slope = mul_div(U256::from(m), supply, scale(), Rounding::Down)?;
+
+                slope
+                    .checked_add(U256::from(p0))
+                    .ok_or(CrazySolError::Overflow.into())
+            }
+            CurveKind::Exponential { p0, growth_rate } => {
+                let exponent = mul_div(U256::from(growth_rate), supply, scale(), Rounding::Down)?;
+                let growth = exp_fixed(exponent, Rounding::Down)?;
+
+                mul_div(U256::from(p0), growth, scale(), Rounding::Down)
+            }
+            CurveKind::Sigmoid { cap, midpoint, steepness } => {
+                let (below_midpoint, exponent) = sigmoid_exponent(supply, midpoint, steepness, Rounding::Down)?;
+
+                let decay = if !below_midpoint {
+                    exp_neg_fixed(exponent, Rounding::Up)?
+                } else if exponent > U256::from(MAX_EXP_INPUT) {
+                    return Ok(U256::zero());
+                } else {
+                    exp_fixed(exponent, Rounding::Up)?
+                };
+
+                mul_div(U256::from(cap), scale(), scale() + decay, Rounding::Down)
+            }
+            CurveKind::PiecewiseLinear { breakpoints, breakpoint_count } => {
+                let points = &breakpoints[..breakpoint_count as usize];
+
+                for pair in points.windows(2) {
+                    let (start, end) = (pair[0], pair[1]);
+                    if supply < U256::from(end.supply) {
+                        let length = U256::from(end.supply - start.supply);
+                        let rise = U256::from(end.potency - start.potency);
+                        let covered = supply - U256::from(start.supply);
+
+                        return Ok(U256::from(start.potency) + mul_div(rise, covered, length, Rounding::Down)?);
+                    }
+                }
+
+                Ok(U256::from(points[points.len() - 1].potency))
+            }
+        }
+    }
+
+    // Fixed-point lamports needed to centrifuge the supply up from zero.
+    pub fn cumulative_cost(&self, supply: U256, rounding: Rounding) -> Result<U256> {
+        match *self {
+            CurveKind::Linear { p0, m } => {
+                // (2 * S * p0 * x + m * x^2) / (2 * S^2)
+                let base = checked_product(checked_product(U256::from(2) * scale(), U256::from(p0))?, supply)?;
+                let slope = checked_product(checked_product(U256::from(m), supply)?, supply)?;
+                let doubled = base
+                    .checked_add(slope)
+                    .ok_or(CrazySolError::Overflow)?;
+
+                div_rounding(doubled, U256::from(2) * scale() * scale(), rounding)
+            }
+            CurveKind::Exponential { p0, growth_rate } => {
+                // p0 * (e^(g * x) - 1) / g
+                let exponent = mul_div(U256::from(growth_rate), supply, scale(), rounding)?;
+                let growth = exp_fixed(exponent, rounding)?.saturating_sub(scale());
+
+                mul_div(U256::from(p0), growth, U256::from(growth_rate), rounding)
+            }
+            CurveKind::Sigmoid { cap, midpoint, steepness } => {
+                // cap * (softplus(k * (x - mid)) - softplus(-k * mid)) / k
+                let (below_midpoint, exponent) = sigmoid_exponent(supply, midpoint, steepness, rounding)?;
+                let (_, origin_exponent) = sigmoid_exponent(U256::zero(), midpoint, steepness, rounding.opposite())?;
+
+                let area = softplus(below_midpoint, exponent, rounding)?
+                    .saturating_sub(softplus(true, origin_exponent, rounding.opposite())?);
+
+                mul_div(U256::from(cap), area, U256::from(steepness), rounding)
+            }
+            CurveKind::PiecewiseLinear { breakpoints, breakpoint_count } => {
+                let points = &breakpoints[..breakpoint_count as usize];
+                let mut cost = U256::zero();
+
+                for pair in points.windows(2) {
+                    let (start, end) = (pair[0], pair[1]);
+                    let start_supply = U256::from(start.supply);
+                    if supply <= start_supply {
+                        return Ok(cost);
+                    }
+
+                    let covered = supply.min(U256::from(end.supply)) - start_supply;
+                    cost = cost
+                        .checked_add(segment_cost(&start, &end, covered, rounding)?)
+                        .ok_or(CrazySolError::Overflow)?;
+                }
+
+                let last = points[points.len() - 1];
+                if supply > U256::from(last.supply) {
+                    let flat = mul_div(supply - U256::from(last.supply), U256::from(last.potency), scale(), rounding)?;
+                    cost = cost
+                        .checked_add(flat)
+                        .ok_or(CrazySolError::Overflow)?;
+                }
+
+                Ok(cost)
+            }
+        }
+    }
+
+    // Inverse of `cumulative_cost`, always rounded down.
+    pub fn supply_for_cumulative_cost(&self, cost: U256) -> Result<U256> {
+        match *self {
+            CurveKind::Linear { p0, m } => {
+                // S * (sqrt(p0^2 + 2 * m * C) - p0) / m
+                let base = U256::from(p0);
+                let discriminant = checked_product(base, base)?
+                    .checked_add(checked_product(U256::from(2) * U256::from(m), cost)?)
+                    .ok_or(CrazySolError::Overflow)?;
+
+                mul_div(sqrt_u256(discriminant) - base, scale(), U256::from(m), Rounding::Down)
+            }
+            CurveKind::Exponential { p0, growth_rate } => {
+                // ln(1 + C * g / p0) / g
+                let growth = mul_div(cost, U256::from(growth_rate), U256::from(p0), Rounding::Down)? + scale();
+                let exponent = ln_fixed(growth, Rounding::Down)?;
+
+                mul_div(exponent, scale(), U256::from(growth_rate), Rounding::Down)
+            }
+            CurveKind::Sigmoid { cap, midpoint, steepness } => {
+                let (_, origin_exponent) = sigmoid_exponent(U256::zero(), midpoint, steepness, Rounding::Up)?;
+
+                let area = mul_div(cost, U256::from(steepness), U256::from(cap), Rounding::Down)?
+                    + softplus(true, origin_exponent, Rounding::Down)?;
+                let (below_midpoint, exponent) = inverse_softplus(area)?;
+
+                let midpoint = U256::from(midpoint);
+                if below_midpoint {
+                    let offset = mul_div(exponent, scale(), U256::from(steepness), Rounding::Up)?;
+                    Ok(midpoint.saturating_sub(offset))
+                } else {
+                    let offset = mul_div(exponent, scale(), U256::from(steepness), Rounding::Down)?;
+                    Ok(midpoint + offset)
+                }
+            }
+            CurveKind::PiecewiseLinear { breakpoints, breakpoint_count } => {
+                let points = &breakpoints[..breakpoint_count as usize];
+                let mut remaining = cost;
+
+                for pair in points.windows(2) {
+                    let (start, end) = (pair[0], pair[1]);
+                    let length = U256::from(end.supply - start.supply);
+                    let segment = segment_cost(&start, &end, length, Rounding::Up)?;
+
+                    if remaining < segment {
+                        return Ok(U256::from(start.supply) + segment_supply(&start, &end, remaining)?);
+                    }
+                    remaining -= segment;
+                }
+
+                let last = points[points.len() - 1];
+                Ok(U256::from(last.supply) + mul_div(remaining, scale(), U256::from(last.potency), Rounding::Down)?)
+            }
+        }
+    }
 }
 
-pub fn compute_rate_of_centrifugation(supply: u128, deposit: u128, p0: u32, m: u8) -> u128 {
-    let s = U256::from(supply);
-    let p0 = U256::from(p0);
-    let m = U256::from(m);
-    let deposit = U256::from(deposit);
+// Signed fixed-point k * (x - midpoint) as (is_negative, magnitude), rounded
+// so that the signed value moves in the requested direction.
+fn sigmoid_exponent(supply: U256, midpoint: u128, steepness: u64, rounding: Rounding) -> Result<(bool, U256)> {
+    let midpoint = U256::from(midpoint);
+    if supply >= midpoint {
+        Ok((false, mul_div(supply - midpoint, U256::from(steepness), scale(), rounding)?))
+    } else {
+        Ok((true, mul_div(midpoint - supply, U256::from(steepness), scale(), rounding.opposite())?))
+    }
+}
+
+// ln(1 + e^z) = max(z, 0) + ln(1 + e^-|z|)
+fn softplus(is_negative: bool, magnitude: U256, rounding: Rounding) -> Result<U256> {
+    let tail = ln_fixed(scale() + exp_neg_fixed(magnitude, rounding)?, rounding)?;
+    if is_negative {
+        Ok(tail)
+    } else {
+        magnitude
+            .checked_add(tail)
+            .ok_or(CrazySolError::Overflow.into())
+    }
+}
 
-    let a = p0 + m * s;
-    let sqrt_term = (a * a) + (U256::from(2) * m * deposit);
-    let sqrt_val = sqrt_u256(sqrt_term);
-    let delta_s = (sqrt_val - a) / m;
+// ln(e^y - 1) = y - ln(1 / (1 - e^-y)), rounded down.
+fn inverse_softplus(value: U256) -> Result<(bool, U256)> {
+    let complement = scale().saturating_sub(exp_neg_fixed(value, Rounding::Up)?);
+    require!(!complement.is_zero(), CrazySolError::Overflow);
 
-    delta_s.as_u128()
+    let penalty = ln_fixed(div_rounding(scale() * scale(), complement, Rounding::Up)?, Rounding::Up)?;
+    if value >= penalty {
+        Ok((false, value - penalty))
+    } else {
+        Ok((true, penalty - value))
+    }
 }
 
+// (2 * L * d * p + rise * d^2) / (2 * L * S)
+fn segment_cost(start: &CurveBreakpoint, end: &CurveBreakpoint, covered: U256, rounding: Rounding) -> Result<U256> {
+    let length = U256::from(end.supply - start.supply);
+    let rise = U256::from(end.potency - start.potency);
+
+    let base = checked_product(checked_product(U256::from(2) * length, covered)?, U256::from(start.potency))?;
+    let slope = checked_product(checked_product(rise, covered)?, covered)?;
+    let doubled = base
+        .checked_add(slope)
+        .ok_or(CrazySolError::Overflow)?;
+
+    div_rounding(doubled, U256::from(2) * length * scale(), rounding)
+}
+
+// L * (sqrt(p^2 + 2 * rise * C * S / L) - p) / rise, rounded down.
+fn segment_supply(start: &CurveBreakpoint, end: &CurveBreakpoint, cost: U256) -> Result<U256> {
+    let length = U256::from(end.supply - start.supply);
+    let rise = U256::from(end.potency - start.potency);
+    let base = U256::from(start.potency);
+
+    if rise.is_zero() {
+        return mul_div(cost, scale(), base, Rounding::Down);
+    }
+
+    let discriminant = checked_product(base, base)?
+        .checked_add(mul_div(checked_product(U256::from(2) * rise, cost)?, scale(), length, Rounding::Down)?)
+        .ok_or(CrazySolError::Overflow)?;
+
+    mul_div(sqrt_u256(discriminant) - base, length, rise, Rounding::Down)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{states::ScientistState, utils::fixed_point::FIXED_POINT_SCALE};
+
+    const PILL: u128 = FIXED_POINT_SCALE;
+    const LAMPORTS_PER_SOL: u128 = 1_000_000_000;
+
+    fn breakpoint(pills: u128, lamports_per_pill: u128) -> CurveBreakpoint {
+        CurveBreakpoint {
+            supply: pills * PILL,
+            potency: lamports_per_pill * FIXED_POINT_SCALE,
+        }
+    }
+
+    fn curves() -> Vec<CurveKind> {
+        let mut breakpoints = [CurveBreakpoint::default(); MAX_CURVE_BREAKPOINTS];
+        breakpoints[..4].copy_from_slice(&[
+            breakpoint(0, 1_000),
+            breakpoint(100_000, 2_000),
+            breakpoint(300_000, 2_000),
+            breakpoint(600_000, 5_000),
+        ]);
+
+        vec![
+            CurveKind::Linear { p0: 1_000 * FIXED_POINT_SCALE, m: 10 * FIXED_POINT_SCALE / 1_000 },
+            CurveKind::Exponential { p0: 1_000 * FIXED_POINT_SCALE, growth_rate: 1_000_000 },
+            CurveKind::Sigmoid { cap: 10_000 * FIXED_POINT_SCALE, midpoint: 500_000 * PILL, steepness: 10_000_000 },
+            CurveKind::PiecewiseLinear { breakpoints, breakpoint_count: 4 },
+        ]
+    }
+
+    fn supplies() -> Vec<u128> {
+        vec![
+            0,
+            1,
+            PILL / 3,
+            PILL,
+            12_345 * PILL + 678,
+            100_000 * PILL,
+            250_000 * PILL,
+            499_999 * PILL,
+            500_000 * PILL,
+            600_000 * PILL + 1,
+            750_000 * PILL,
+            1_000_000 * PILL,
+        ]
+    }
+
+    #[test]
+    fn every_test_curve_is_valid() {
+        for curve in curves() {
+            curve.validate().unwrap();
+        }
+    }
+
+    #[test]
+    fn inverse_never_overshoots_the_supply() {
+        for curve in curves() {
+            for supply in supplies() {
+                for rounding in [Rounding::Down, Rounding::Up] {
+                    let cost = curve.cumulative_cost(U256::from(supply), rounding).unwrap();
+                    let recovered = curve.supply_for_cumulative_cost(cost).unwrap();
+
+                    if rounding == Rounding::Down {
+                        assert!(recovered <= U256::from(supply), "{recovered} > {supply}");
+                    }
+                    // Exact up to the curve's own rounding error.
+                    assert!(U256::from(supply) - recovered.min(U256::from(supply)) <= U256::from(PILL / 1_000));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn potency_is_monotone() {
+        for curve in curves() {
+            let mut previous = U256::zero();
+            for step in 0..=200u128 {
+                let potency = curve.potency_at(U256::from(step * 5_000 * PILL)).unwrap();
+                assert!(potency >= previous, "potency fell at step {step}");
+                previous = potency;
+            }
+        }
+    }
+
+    #[test]
+    fn cumulative_cost_rounds_up_at_least_as_high_as_down() {
+        for curve in curves() {
+            for supply in supplies() {
+                let down = curve.cumulative_cost(U256::from(supply), Rounding::Down).unwrap();
+                let up = curve.cumulative_cost(U256::from(supply), Rounding::Up).unwrap();
+                assert!(up >= down);
+            }
+        }
+    }
+
+    // Curve Pills only; bonus Pills are held back by the scientist's cost basis.
+    #[test]
+    fn buy_then_sell_never_returns_more_than_deposited() {
+        for curve in curves() {
+            for supply in supplies() {
+                for deposit in [1, 1_000, LAMPORTS_PER_SOL / 20, LAMPORTS_PER_SOL, 500 * LAMPORTS_PER_SOL] {
+                    let pills = compute_rate_of_centrifugation(supply, deposit, &curve).unwrap();
+                    let payout = compute_rate_of_dissolution(supply + pills, pills, &curve).unwrap();
+
+                    assert!(payout <= deposit, "paid {payout} for a {deposit} deposit at supply {supply}");
+                }
+            }
+        }
+    }
+
+    fn fresh_scientist() -> ScientistState {
+        ScientistState {
+            owner: Pubkey::default(),
+            username: String::new(),
+            recruiter: None,
+            owned_pill: 0,
+            owned_pcrazy: 0,
+            is_first_injection_done: false,
+            is_first_mutation_done: false,
+            sol_injected: 0,
+            last_streak_timestamp: 0,
+            current_streak: 0,
+            last_distillation_timestamp: 0,
+            distillable_yield: 0,
+            test_subjects_count: 0,
+            specimens_count: 0,
+            samples_count: 0,
+            earned_sol: 0,
+            earned_sol_from_research: 0,
+            yield_debt: 0,
+            cultures_count: 0,
+            curve_cost_basis: 0,
+            reserved: [0; 100],
+        }
+    }
+
+    #[test]
+    fn bonus_pills_never_dissolve_for_more_than_deposited() {
+        let mut bonus_beats_deposit = false;
+
+        for curve in curves() {
+            for supply in supplies() {
+                for deposit in [LAMPORTS_PER_SOL / 20, LAMPORTS_PER_SOL, 500 * LAMPORTS_PER_SOL] {
+                    let pills = compute_rate_of_centrifugation(supply, deposit, &curve).unwrap();
+                    let bonus = pills * 10 / 100;
+                    let payout = compute_rate_of_dissolution(supply + pills + bonus, pills + bonus, &curve).unwrap();
+                    bonus_beats_deposit |= payout > deposit;
+
+                    let mut scientist = fresh_scientist();
+                    scientist.record_curve_cost(deposit as u64).unwrap();
+                    let redeemed = scientist.redeem_curve_cost(payout as u64);
+
+                    assert_eq!(redeemed.is_ok(), payout <= deposit);
+                }
+            }
+        }
 
-fn sqrt_u256(value: U256) -> U256 {
-    if value.is_zero() {
-        return U256::zero();
+        assert!(bonus_beats_deposit, "the curve alone should pay out more than the deposit for bonus Pills");
     }
 
-    let mut z = value;
-    let mut x = (value >> 1) + U256::one();
-    while x < z {
-        z = x;
-        x = (value / x + x) >> 1;
+    #[test]
+    fn dissolution_rejects_burning_more_than_the_supply() {
+        for curve in curves() {
+            assert!(compute_rate_of_dissolution(PILL, 2 * PILL, &curve).is_err());
+        }
     }
-    z
 }
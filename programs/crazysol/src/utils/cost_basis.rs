@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::ScientistState};
+
+impl ScientistState {
+    pub fn record_curve_cost(&mut self, lamports: u64) -> Result<()> {
+        self.curve_cost_basis = self.curve_cost_basis
+            .checked_add(lamports)
+            .ok_or(CrazySolError::Overflow)?;
+        Ok(())
+    }
+
+    // Bonus Pills sit on the curve without having paid for it, so dissolve can
+    // only ever hand back what the scientist put in.
+    pub fn redeem_curve_cost(&mut self, lamports: u64) -> Result<()> {
+        self.curve_cost_basis = self.curve_cost_basis
+            .checked_sub(lamports)
+            .ok_or(CrazySolError::DissolveExceedsCostBasis)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::{Council, Proposal}};
+
+pub fn validate_council_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= Council::MAX_MEMBERS,
+        CrazySolError::InvalidCouncil
+    );
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        CrazySolError::InvalidCouncil
+    );
+
+    for (index, member) in members.iter().enumerate() {
+        require!(
+            !members[..index].contains(member),
+            CrazySolError::InvalidCouncil
+        );
+    }
+
+    Ok(())
+}
+
+impl Council {
+    pub fn member_index(&self, key: &Pubkey) -> Result<usize> {
+        self.members
+            .iter()
+            .position(|member| member == key)
+            .ok_or(CrazySolError::NotCouncilMember.into())
+    }
+}
+
+impl Proposal {
+    // Approvals are a bitmap over Council::members, so they only stay meaningful
+    // while the member list they were collected against is unchanged.
+    pub fn approve(&mut self, council: &Council, member: &Pubkey) -> Result<()> {
+        require!(!self.executed, CrazySolError::ProposalAlreadyExecuted);
+        require!(
+            self.council_version == council.version,
+            CrazySolError::StaleProposal
+        );
+
+        let index = council.member_index(member)?;
+        self.approvals |= 1 << index;
+        Ok(())
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+}
@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CrazySolError;
+
+pub use wide::U256;
+
+mod wide {
+    use uint::construct_uint;
+
+    construct_uint! {
+        pub struct U256(4);
+    }
+}
+
+// Pill amounts and curve parameters are stored with 12 decimals.
+pub const FIXED_POINT_SCALE: u128 = 1_000_000_000_000;
+pub const MAX_EXP_INPUT: u128 = 120 * FIXED_POINT_SCALE;
+const LN_2: u128 = 693_147_180_559;
+const LN_2_ROUNDED_UP: u128 = 693_147_180_560;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+impl Rounding {
+    pub fn opposite(self) -> Self {
+        match self {
+            Rounding::Down => Rounding::Up,
+            Rounding::Up => Rounding::Down,
+        }
+    }
+}
+
+pub fn scale() -> U256 {
+    U256::from(FIXED_POINT_SCALE)
+}
+
+pub fn to_u128(value: U256) -> Result<u128> {
+    require!(
+        value <= U256::from(u128::MAX),
+        CrazySolError::Overflow
+    );
+    Ok(value.as_u128())
+}
+
+pub fn checked_product(a: U256, b: U256) -> Result<U256> {
+    a.checked_mul(b).ok_or(CrazySolError::Overflow.into())
+}
+
+pub fn div_rounding(numerator: U256, denominator: U256, rounding: Rounding) -> Result<U256> {
+    require!(!denominator.is_zero(), CrazySolError::Overflow);
+
+    let quotient = numerator / denominator;
+    if rounding == Rounding::Up && !(numerator % denominator).is_zero() {
+        return Ok(quotient + U256::one());
+    }
+    Ok(quotient)
+}
+
+pub fn mul_div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Result<U256> {
+    div_rounding(checked_product(a, b)?, denominator, rounding)
+}
+
+pub fn sqrt_u256(value: U256) -> U256 {
+    if value.is_zero() {
+        return U256::zero();
+    }
+
+    let mut z = value;
+    let mut x = (value >> 1) + U256::one();
+    while x < z {
+        z = x;
+        x = (value / x + x) >> 1;
+    }
+    z
+}
+
+// e^x for a non-negative fixed-point x. Every truncated term costs at most one
+// unit, so rounding up adds one unit per term before scaling back up. The
+// range reduction uses ln(2) rounded against the requested direction, since
+// its error is multiplied by 2^halvings.
+pub fn exp_fixed(x: U256, rounding: Rounding) -> Result<U256> {
+    require!(
+        x <= U256::from(MAX_EXP_INPUT),
+        CrazySolError::Overflow
+    );
+
+    let ln_2 = U256::from(match rounding {
+        Rounding::Down => LN_2_ROUNDED_UP,
+        Rounding::Up => LN_2,
+    });
+    let halvings = x / ln_2;
+    let remainder = x - halvings * ln_2;
+
+    let mut term = scale();
+    let mut sum = scale();
+    let mut k = 1u64;
+    loop {
+        term = term * remainder / (scale() * U256::from(k));
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        k += 1;
+    }
+
+    if rounding == Rounding::Up {
+        sum += U256::from(k + 1);
+    }
+
+    Ok(sum << halvings.as_usize())
+}
+
+// e^-x for a non-negative fixed-point x, flushing to zero once it underflows.
+pub fn exp_neg_fixed(x: U256, rounding: Rounding) -> Result<U256> {
+    if x > U256::from(MAX_EXP_INPUT) {
+        return Ok(match rounding {
+            Rounding::Down => U256::zero(),
+            Rounding::Up => U256::one(),
+        });
+    }
+    div_rounding(scale() * scale(), exp_fixed(x, rounding.opposite())?, rounding)
+}
+
+// ln(x) for a fixed-point x >= 1.
+pub fn ln_fixed(x: U256, rounding: Rounding) -> Result<U256> {
+    require!(
+        x >= scale(),
+        CrazySolError::Overflow
+    );
+
+    let doublings = (x / scale()).bits() - 1;
+    let y = x >> doublings;
+
+    let z = (y - scale()) * scale() / (y + scale());
+    let z_squared = z * z / scale();
+
+    let mut term = z;
+    let mut sum = U256::zero();
+    let mut k = 1u64;
+    while !term.is_zero() {
+        sum += term / U256::from(k);
+        term = term * z_squared / scale();
+        k += 2;
+    }
+
+    let mut result = U256::from(doublings) * U256::from(LN_2) + sum * U256::from(2);
+    if rounding == Rounding::Up {
+        result += U256::from(k + doublings as u64 + 2);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Truncated fixed-point value of e.
+    const E: u128 = 2_718_281_828_459;
+
+    fn fixed(value: u128) -> U256 {
+        U256::from(value * FIXED_POINT_SCALE)
+    }
+
+    // `exact_floor` is the exact result truncated to 12 decimals, with the true
+    // value strictly between it and the next unit.
+    fn assert_brackets<F>(f: F, x: U256, exact_floor: u128, tolerance: u128)
+    where
+        F: Fn(U256, Rounding) -> Result<U256>,
+    {
+        let down = f(x, Rounding::Down).unwrap().as_u128();
+        let up = f(x, Rounding::Up).unwrap().as_u128();
+
+        assert!(down <= exact_floor, "rounded down past the exact value: {down} > {exact_floor}");
+        assert!(up > exact_floor, "rounded up below the exact value: {up} <= {exact_floor}");
+        assert!(exact_floor - down <= tolerance, "down too far off: {down} vs {exact_floor}");
+        assert!(up - exact_floor <= tolerance, "up too far off: {up} vs {exact_floor}");
+    }
+
+    #[test]
+    fn div_rounding_rounds_in_the_requested_direction() {
+        let (seven, two) = (U256::from(7), U256::from(2));
+
+        assert_eq!(div_rounding(seven, two, Rounding::Down).unwrap(), U256::from(3));
+        assert_eq!(div_rounding(seven, two, Rounding::Up).unwrap(), U256::from(4));
+        assert_eq!(div_rounding(U256::from(8), two, Rounding::Up).unwrap(), U256::from(4));
+        assert!(div_rounding(seven, U256::zero(), Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction() {
+        let down = mul_div(U256::from(10), U256::from(10), U256::from(3), Rounding::Down).unwrap();
+        let up = mul_div(U256::from(10), U256::from(10), U256::from(3), Rounding::Up).unwrap();
+
+        assert_eq!(down, U256::from(33));
+        assert_eq!(up, U256::from(34));
+        assert!(mul_div(U256::MAX, U256::from(2), U256::one(), Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn exp_fixed_matches_known_values() {
+        assert_eq!(exp_fixed(U256::zero(), Rounding::Down).unwrap(), scale());
+        assert_brackets(exp_fixed, fixed(1), E, 50);
+        assert_brackets(exp_fixed, fixed(10), 22_026_465_794_806_716, 1_000_000);
+        assert_brackets(exp_fixed, U256::from(FIXED_POINT_SCALE / 2), 1_648_721_270_700, 50);
+    }
+
+    #[test]
+    fn exp_fixed_accepts_inputs_up_to_the_maximum() {
+        assert!(exp_fixed(U256::from(MAX_EXP_INPUT), Rounding::Up).is_ok());
+        assert!(exp_fixed(U256::from(MAX_EXP_INPUT + 1), Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn exp_neg_fixed_matches_known_values() {
+        assert_eq!(exp_neg_fixed(U256::zero(), Rounding::Up).unwrap(), scale());
+        assert!(exp_neg_fixed(U256::zero(), Rounding::Down).unwrap() <= scale());
+        assert_brackets(exp_neg_fixed, fixed(1), 367_879_441_171, 10);
+        assert_brackets(exp_neg_fixed, fixed(2), 135_335_283_236, 10);
+    }
+
+    #[test]
+    fn exp_neg_fixed_flushes_past_the_maximum() {
+        let past_max = U256::from(MAX_EXP_INPUT + 1);
+
+        assert_eq!(exp_neg_fixed(past_max, Rounding::Down).unwrap(), U256::zero());
+        assert_eq!(exp_neg_fixed(past_max, Rounding::Up).unwrap(), U256::one());
+    }
+
+    #[test]
+    fn ln_fixed_matches_known_values() {
+        assert_eq!(ln_fixed(scale(), Rounding::Down).unwrap(), U256::zero());
+        assert_brackets(ln_fixed, fixed(2), LN_2, 10);
+        assert_brackets(ln_fixed, U256::from(E), 999_999_999_999, 20);
+        assert_brackets(ln_fixed, fixed(1_000_000), 13_815_510_557_964, 100);
+    }
+
+    #[test]
+    fn ln_fixed_rejects_inputs_below_one() {
+        assert!(ln_fixed(scale() - U256::one(), Rounding::Down).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{compute_pill_potency, errors::CrazySolError, states::{FormulaBounds, ReactionFormula}, utils::{mul_div, Rounding, U256}};
+
+impl FormulaBounds {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_initial_potency > 0
+                && self.min_initial_potency <= self.max_initial_potency
+                && self.min_first_injection_bonus <= self.max_first_injection_bonus
+                && self.min_first_mutation_bonus <= self.max_first_mutation_bonus
+                && self.max_potency_change_bps > 0
+                && self.max_bonus_change_bps > 0,
+            CrazySolError::InvalidFormulaBounds
+        );
+        Ok(())
+    }
+
+    // `current` is the formula being replaced together with the live Pill supply,
+    // or None when there is nothing to compare against yet.
+    pub fn check_reaction_formula(
+        &self,
+        reaction_formula: &ReactionFormula,
+        current: Option<(&ReactionFormula, u128)>,
+    ) -> Result<()> {
+        reaction_formula.bonding_curve.validate()?;
+
+        let initial_potency = compute_pill_potency(0, &reaction_formula.bonding_curve)?;
+        require!(
+            (self.min_initial_potency..=self.max_initial_potency).contains(&initial_potency),
+            CrazySolError::InitialPotencyOutOfBounds
+        );
+        require!(
+            (self.min_first_injection_bonus..=self.max_first_injection_bonus)
+                .contains(&reaction_formula.first_injection_bonus),
+            CrazySolError::FirstInjectionBonusOutOfBounds
+        );
+        require!(
+            (self.min_first_mutation_bonus..=self.max_first_mutation_bonus)
+                .contains(&reaction_formula.first_mutation_bonus),
+            CrazySolError::FirstMutationBonusOutOfBounds
+        );
+
+        if let Some((current_formula, pill_supply)) = current {
+            let current_potency = compute_pill_potency(pill_supply, &current_formula.bonding_curve)?;
+            let new_potency = compute_pill_potency(pill_supply, &reaction_formula.bonding_curve)?;
+
+            let max_change = mul_div(
+                U256::from(current_potency),
+                U256::from(self.max_potency_change_bps),
+                U256::from(10_000u64),
+                Rounding::Down
+            )?;
+
+            require!(
+                U256::from(current_potency.abs_diff(new_potency)) <= max_change,
+                CrazySolError::PotencyChangeTooLarge
+            );
+
+            self.check_bonus_change(
+                current_formula.first_injection_bonus,
+                reaction_formula.first_injection_bonus,
+                self.max_first_injection_bonus - self.min_first_injection_bonus
+            )?;
+            self.check_bonus_change(
+                current_formula.first_mutation_bonus,
+                reaction_formula.first_mutation_bonus,
+                self.max_first_mutation_bonus - self.min_first_mutation_bonus
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Bonuses may start at zero, so their step is a share of the allowed range
+    // rather than of the current value.
+    fn check_bonus_change(&self, current_bonus: u128, new_bonus: u128, bonus_range: u128) -> Result<()> {
+        let max_change = mul_div(
+            U256::from(bonus_range),
+            U256::from(self.max_bonus_change_bps),
+            U256::from(10_000u64),
+            Rounding::Down
+        )?;
+
+        require!(
+            U256::from(current_bonus.abs_diff(new_bonus)) <= max_change,
+            CrazySolError::BonusChangeTooLarge
+        );
+        Ok(())
+    }
+}
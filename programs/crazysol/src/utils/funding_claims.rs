@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::FundingCampaign};
+
+impl FundingCampaign {
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed_bitmap[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn record_claim(&mut self, index: u32, amount: u64) -> Result<()> {
+        require!(
+            index < self.max_claims,
+            CrazySolError::InvalidMerkleProof
+        );
+        require!(
+            !self.is_claimed(index),
+            CrazySolError::FundingAlreadyClaimed
+        );
+
+        let claimed_amount = self.claimed_amount
+            .checked_add(amount)
+            .ok_or(CrazySolError::Overflow)?;
+        require!(
+            claimed_amount <= self.budget,
+            CrazySolError::FundingCampaignExhausted
+        );
+
+        self.claimed_amount = claimed_amount;
+        self.claimed_bitmap[index as usize / 8] |= 1 << (index % 8);
+
+        Ok(())
+    }
+}
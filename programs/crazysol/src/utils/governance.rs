@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_GOVERNANCE_DELAY,
+    errors::CrazySolError,
+    events::QueueParameterChangeEvent,
+    states::{LaboratoryState, ParameterChange, PendingChange},
+};
+
+pub fn validate_governance_delay(governance_delay: i64) -> Result<()> {
+    require!(
+        (0..=MAX_GOVERNANCE_DELAY).contains(&governance_delay),
+        CrazySolError::InvalidGovernanceDelay
+    );
+    Ok(())
+}
+
+pub fn queue_parameter_change(
+    pending_change: &mut PendingChange,
+    laboratory_state: &LaboratoryState,
+    change: ParameterChange,
+    proposer: Pubkey,
+) -> Result<()> {
+    let queued_at = Clock::get()?.unix_timestamp;
+    let executable_at = queued_at
+        .checked_add(laboratory_state.governance_delay)
+        .ok_or(CrazySolError::Overflow)?;
+
+    pending_change.change = change;
+    pending_change.proposer = proposer;
+    pending_change.queued_at = queued_at;
+    pending_change.executable_at = executable_at;
+
+    emit!(QueueParameterChangeEvent {
+        change,
+        proposer,
+        queued_at,
+        executable_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CrazySolError;
+
+// Lowercases a human-facing handle and rejects anything outside [a-z0-9_-] or
+// the given length bounds, so that "Alice" and "alice" resolve to the same PDA.
+pub fn normalize_handle(handle: &str, min_length: usize, max_length: usize) -> Result<String> {
+    let normalized = handle.trim().to_ascii_lowercase();
+
+    require!(
+        normalized.len() >= min_length && normalized.len() <= max_length,
+        CrazySolError::InvalidHandle
+    );
+    require!(
+        normalized.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-'),
+        CrazySolError::InvalidHandle
+    );
+
+    Ok(normalized)
+}
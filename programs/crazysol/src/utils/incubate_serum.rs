@@ -1,53 +1,43 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::SECONDS_IN_24H, errors::CrazySolError, states::{LaboratoryState, ScientistState}};
+use crate::{constants::YIELD_INDEX_PRECISION, errors::CrazySolError, states::{ReactorState, ScientistState}, utils::{to_u128, U256}};
 
 impl ScientistState {
-    pub fn incubate_serum(&mut self, laboratory_state: &LaboratoryState, current_ts: i64) -> Result<()> {
-        if self.owned_pill == 0 {
-            return Ok(());
-        }
+    pub fn incubate_serum(&mut self, reactor_state: &ReactorState, current_ts: i64) -> Result<()> {
+        let accrued_yield = self.accrued_yield(reactor_state)?;
+        let pending_yield = self.pending_yield(reactor_state)?;
 
-        let elapsed = current_ts
-            .checked_sub(self.last_distillation_timestamp)
+        self.distillable_yield = self.distillable_yield
+            .checked_add(pending_yield)
             .ok_or(CrazySolError::Overflow)?;
-        if elapsed <= 0 {
-            return Ok(());
-        }
+        self.yield_debt = accrued_yield;
+        self.last_distillation_timestamp = current_ts;
 
-        let base_rate = laboratory_state.reaction_formula.reward_rate_per_pill;
+        Ok(())
+    }
 
-        let min_daily_yield = (100_000u64)
-            .checked_mul(laboratory_state.reaction_formula.min_daily_yield_bps)
-            .ok_or(CrazySolError::Overflow)?
-            .checked_div(10_000) 
+    pub fn pending_yield(&self, reactor_state: &ReactorState) -> Result<u64> {
+        let pending_yield = self.accrued_yield(reactor_state)?
+            .checked_sub(self.yield_debt)
             .ok_or(CrazySolError::Overflow)?;
 
-        let min_rate_per_sec = min_daily_yield
-            .checked_div(SECONDS_IN_24H)
-            .ok_or(CrazySolError::Overflow)?;
+        pending_yield
+            .try_into()
+            .map_err(|_| CrazySolError::Overflow.into())
+    }
 
-        let adjusted_rate = if base_rate < min_rate_per_sec {
-            min_rate_per_sec
-        } else {
-            base_rate
-        };
+    // Must be called whenever owned_pill changes, after incubate_serum.
+    pub fn sync_yield_debt(&mut self, reactor_state: &ReactorState) -> Result<()> {
+        self.yield_debt = self.accrued_yield(reactor_state)?;
+        Ok(())
+    }
 
-        let yield_amount = self.owned_pill
-            .checked_mul(adjusted_rate as u128)
+    fn accrued_yield(&self, reactor_state: &ReactorState) -> Result<u128> {
+        let accrued_yield = U256::from(self.owned_pill)
+            .checked_mul(U256::from(reactor_state.acc_yield_per_pill))
             .ok_or(CrazySolError::Overflow)?
-            .checked_mul(elapsed as u128)
-            .ok_or(CrazySolError::Overflow)?;
+            / U256::from(YIELD_INDEX_PRECISION);
 
-        let yield_u64: u64 = yield_amount
-            .try_into()
-            .map_err(|_| CrazySolError::Overflow)?;
-
-        self.distillable_yield = self.distillable_yield
-            .checked_add(yield_u64)
-            .ok_or(CrazySolError::Overflow)?;
-        self.last_distillation_timestamp = current_ts;
-
-        Ok(())
+        to_u128(accrued_yield)
     }
 }
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::LaboratoryConfig, validate_governance_delay, validate_research_reward_schedule};
+
+pub fn validate_containment_tax_bps(containment_tax_bps: u16, max_containment_tax_bps: u16) -> Result<()> {
+    require!(
+        containment_tax_bps <= max_containment_tax_bps,
+        CrazySolError::InvalidFeePercentage
+    );
+    Ok(())
+}
+
+impl LaboratoryConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_containment_tax_bps <= 10_000,
+            CrazySolError::InvalidFeePercentage
+        );
+        validate_containment_tax_bps(self.containment_tax_bps, self.max_containment_tax_bps)?;
+
+        self.formula_bounds.validate()?;
+        self.formula_bounds.check_reaction_formula(&self.reaction_formula, None)?;
+
+        validate_research_reward_schedule(&self.research_reward_schedule)?;
+        validate_governance_delay(self.governance_delay)?;
+        self.season_schedule.validate()?;
+        self.vesting_schedule.validate()?;
+
+        require!(
+            self.min_injection_lamports > 0,
+            CrazySolError::InvalidMinInjection
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+// Leaves and inner nodes use distinct prefixes so a node can never be passed off as a leaf.
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+// The laboratory and campaign ids bind a proof to one campaign, so it cannot be replayed elsewhere.
+pub fn funding_leaf(laboratory_id: u64, campaign_id: u64, index: u32, scientist: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        laboratory_id.to_le_bytes().as_ref(),
+        campaign_id.to_le_bytes().as_ref(),
+        index.to_le_bytes().as_ref(),
+        scientist.as_ref(),
+        amount.to_le_bytes().as_ref(),
+    ]).to_bytes()
+}
+
+// Pairs are hashed in sorted order, so proofs carry no left/right flags.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_root = proof.iter().fold(leaf, |node, sibling| {
+        let (first, second) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        hashv(&[NODE_PREFIX, first.as_ref(), second.as_ref()]).to_bytes()
+    });
+
+    computed_root == *root
+}
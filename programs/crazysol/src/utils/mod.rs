@@ -1,8 +1,39 @@
 pub mod incubate_serum;
 pub mod centrifuge;
+pub mod cost_basis;
+pub mod council_approvals;
+pub mod fixed_point;
+pub mod formula_bounds;
+pub mod funding_claims;
+pub mod governance;
+pub mod handles;
+pub mod laboratory_config;
+pub mod merkle;
+pub mod public_funding;
+pub mod research_rewards;
+pub mod reserves_ledger;
+pub mod roles;
+pub mod season;
 pub mod security;
+pub mod slippage;
 pub mod time;
+pub mod username_registry;
+pub mod vesting;
+pub mod yield_index;
 
 pub use centrifuge::*;
+pub use council_approvals::*;
+pub use fixed_point::*;
+pub use governance::*;
+pub use handles::*;
+pub use laboratory_config::*;
+pub use merkle::*;
+pub use public_funding::*;
+pub use research_rewards::*;
+pub use reserves_ledger::*;
+pub use roles::*;
+pub use season::*;
 pub use security::*;
+pub use slippage::*;
 pub use time::*;
+pub use username_registry::*;
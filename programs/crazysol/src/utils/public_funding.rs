@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::ScientistState};
+
+// Credits pCRAZY to a scientist account passed in unchecked, after verifying it
+// is the scientist's PDA in this laboratory. Returns the new balance.
+pub fn credit_public_funding(
+    program_id: &Pubkey,
+    laboratory_id: u64,
+    receiver: &AccountInfo,
+    scientist: Pubkey,
+    amount: u64,
+) -> Result<u64> {
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[ScientistState::SEED, laboratory_id.to_le_bytes().as_ref(), scientist.as_ref()],
+        program_id,
+    );
+
+    if expected_pda != receiver.key() {
+        return Err(CrazySolError::InvalidScientistAddress.into());
+    }
+
+    let mut scientist_data = receiver.try_borrow_mut_data()?;
+
+    let mut scientist_state = ScientistState::try_deserialize(&mut scientist_data.as_ref())
+        .map_err(|_| CrazySolError::InvalidScientist)?;
+
+    require!(
+        scientist_state.owner == scientist,
+        CrazySolError::InvalidScientist
+    );
+
+    scientist_state.owned_pcrazy = scientist_state
+        .owned_pcrazy
+        .checked_add(amount)
+        .ok_or(CrazySolError::Overflow)?;
+
+    scientist_state.try_serialize(&mut scientist_data.as_mut())?;
+
+    Ok(scientist_state.owned_pcrazy)
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_RESEARCH_REWARD_LEVELS, errors::CrazySolError};
+
+pub fn validate_research_reward_schedule(schedule: &[u16]) -> Result<()> {
+    require!(
+        schedule.len() <= MAX_RESEARCH_REWARD_LEVELS,
+        CrazySolError::InvalidResearchRewardSchedule
+    );
+
+    let total_bps: u32 = schedule.iter().map(|&bps| bps as u32).sum();
+    require!(
+        total_bps <= 10_000,
+        CrazySolError::InvalidResearchRewardSchedule
+    );
+
+    Ok(())
+}
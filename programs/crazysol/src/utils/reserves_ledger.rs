@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::ReactorState};
+
+impl ReactorState {
+    pub fn record_inflow(&mut self, lamports: u64) -> Result<()> {
+        self.sol_reserves = self.sol_reserves
+            .checked_add(lamports)
+            .ok_or(CrazySolError::Overflow)?;
+        self.total_lamports_in = self.total_lamports_in
+            .checked_add(lamports)
+            .ok_or(CrazySolError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_outflow(&mut self, lamports: u64) -> Result<()> {
+        self.sol_reserves = self.sol_reserves
+            .checked_sub(lamports)
+            .ok_or(CrazySolError::InsufficientReserves)?;
+        self.total_lamports_out = self.total_lamports_out
+            .checked_add(lamports)
+            .ok_or(CrazySolError::Overflow)?;
+        Ok(())
+    }
+}
+
+// Lamports the Reactor can pay out without dipping below rent exemption or
+// past what the ledger has recorded as received.
+pub fn withdrawable_reserves(reactor_state: &Account<ReactorState>) -> Result<u64> {
+    let reactor_info = reactor_state.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(reactor_info.data_len());
+    let available_balance = reactor_info
+        .lamports()
+        .checked_sub(min_balance)
+        .ok_or(CrazySolError::Overflow)?;
+
+    Ok(available_balance.min(reactor_state.sol_reserves))
+}
+
+pub fn release_reactor_lamports<'info>(
+    reactor_state: &mut Account<'info, ReactorState>,
+    recipient: &AccountInfo<'info>,
+    lamports: u64,
+) -> Result<()> {
+    **reactor_state.to_account_info().try_borrow_mut_lamports()? = reactor_state
+        .to_account_info()
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(CrazySolError::Overflow)?;
+
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(CrazySolError::Overflow)?;
+
+    reactor_state.record_outflow(lamports)
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::{LaboratoryState, RoleGrant}};
+
+// The director implicitly holds every role; anyone else needs a RoleGrant
+// carrying all of the requested bits.
+pub fn require_role(
+    laboratory_state: &LaboratoryState,
+    authority: &Pubkey,
+    role_grant: Option<&Account<RoleGrant>>,
+    role: u8,
+) -> Result<()> {
+    if *authority == laboratory_state.director {
+        return Ok(());
+    }
+
+    let role_grant = role_grant.ok_or(CrazySolError::Unauthorized)?;
+
+    require!(
+        role_grant.grantee == *authority && role_grant.roles & role == role,
+        CrazySolError::Unauthorized
+    );
+
+    Ok(())
+}
+
+// Only the director may hand out or take away the role admin bit itself.
+pub fn require_role_admin_for(
+    laboratory_state: &LaboratoryState,
+    authority: &Pubkey,
+    role_grant: Option<&Account<RoleGrant>>,
+    roles: u8,
+) -> Result<()> {
+    require!(
+        roles != 0 && roles & !RoleGrant::ALL_ROLES == 0,
+        CrazySolError::InvalidRoles
+    );
+
+    if roles & RoleGrant::ROLE_ADMIN != 0 {
+        require!(
+            *authority == laboratory_state.director,
+            CrazySolError::Unauthorized
+        );
+    }
+
+    require_role(laboratory_state, authority, role_grant, RoleGrant::ROLE_ADMIN)
+}
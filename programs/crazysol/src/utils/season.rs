@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::YIELD_INDEX_PRECISION, errors::CrazySolError, states::{LaboratoryState, ReactorState, SeasonSchedule}, utils::{to_u128, U256}};
+
+impl SeasonSchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.start_ts < self.injection_end_ts && self.injection_end_ts <= self.settlement_ts,
+            CrazySolError::InvalidSeasonSchedule
+        );
+        Ok(())
+    }
+}
+
+pub fn require_season_not_finalized(laboratory_state: &LaboratoryState) -> Result<()> {
+    require!(
+        !laboratory_state.season_finalized,
+        CrazySolError::SeasonFinalized
+    );
+    Ok(())
+}
+
+// Scientists can sign up ahead of the start, but not once injections are closed.
+pub fn require_registration_open(laboratory_state: &LaboratoryState, current_ts: i64) -> Result<()> {
+    require_season_not_finalized(laboratory_state)?;
+    require!(
+        current_ts < laboratory_state.season_schedule.injection_end_ts,
+        CrazySolError::InjectionPeriodEnded
+    );
+    Ok(())
+}
+
+pub fn require_injection_window(laboratory_state: &LaboratoryState, current_ts: i64) -> Result<()> {
+    require!(
+        current_ts >= laboratory_state.season_schedule.start_ts,
+        CrazySolError::SeasonNotStarted
+    );
+    require_registration_open(laboratory_state, current_ts)
+}
+
+impl ReactorState {
+    // Rounds down so the sum of every claim never exceeds the frozen reserves.
+    pub fn compute_final_lamports_per_pill(&self, settlement_reserves: u64) -> Result<u128> {
+        if self.pill_supply == 0 {
+            return Ok(0);
+        }
+
+        to_u128(
+            U256::from(settlement_reserves)
+                .checked_mul(U256::from(YIELD_INDEX_PRECISION))
+                .ok_or(CrazySolError::Overflow)?
+                / U256::from(self.pill_supply),
+        )
+    }
+
+    // Live pro-rata share used by emergency_withdraw before the season is finalized.
+    pub fn pro_rata_payout(&self, settlement_reserves: u64, pill_amount: u128) -> Result<u64> {
+        if self.pill_supply == 0 {
+            return Ok(0);
+        }
+
+        let payout = to_u128(
+            U256::from(settlement_reserves)
+                .checked_mul(U256::from(pill_amount))
+                .ok_or(CrazySolError::Overflow)?
+                / U256::from(self.pill_supply),
+        )?;
+
+        payout
+            .try_into()
+            .map_err(|_| CrazySolError::Overflow.into())
+    }
+
+    pub fn settlement_payout(&self, pill_amount: u128) -> Result<u64> {
+        let payout = to_u128(
+            U256::from(pill_amount)
+                .checked_mul(U256::from(self.final_lamports_per_pill))
+                .ok_or(CrazySolError::Overflow)?
+                / U256::from(YIELD_INDEX_PRECISION),
+        )?;
+
+        payout
+            .try_into()
+            .map_err(|_| CrazySolError::Overflow.into())
+    }
+}
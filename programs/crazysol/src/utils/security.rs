@@ -26,12 +26,35 @@ pub fn require_initialized(laboratory_state: &LaboratoryState) -> Result<()> {
 
 pub fn require_operational(laboratory_state: &LaboratoryState) -> Result<()> {
     require!(
-        !laboratory_state.emergency_lockdown,
+        !laboratory_state.emergency_lockdown && !laboratory_state.emergency_exit,
         CrazySolError::CurrentlyPaused
     );
     Ok(())
 }
 
+impl LaboratoryState {
+    // The emergency lockdown and exit modes still act as master switches over every flag.
+    pub fn is_paused(&self, pause_flag: u8) -> bool {
+        self.emergency_lockdown || self.emergency_exit || self.pause_flags & pause_flag != 0
+    }
+}
+
+pub fn require_not_paused(laboratory_state: &LaboratoryState, pause_flag: u8) -> Result<()> {
+    require!(
+        !laboratory_state.is_paused(pause_flag),
+        CrazySolError::CurrentlyPaused
+    );
+    Ok(())
+}
+
+pub fn validate_pause_flags(pause_flags: u8) -> Result<()> {
+    require!(
+        pause_flags & !LaboratoryState::ALL_PAUSE_FLAGS == 0,
+        CrazySolError::InvalidPauseFlags
+    );
+    Ok(())
+}
+
 pub fn require_innoculation_not_happened(laboratory_state: &LaboratoryState) -> Result<()> {
     require!(
         !laboratory_state.innoculation_happened,
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, utils::FIXED_POINT_SCALE};
+
+pub fn verify_slippage_tolerance(
+    lamports_in: u64,
+    curve_pills_out: u128,
+    total_pills_out: u128,
+    min_pills_out: u128,
+    max_price_per_pill: Option<u64>,
+) -> Result<()> {
+    require!(
+        total_pills_out >= min_pills_out,
+        CrazySolError::SlippageExceeded
+    );
+
+    if let Some(max_price) = max_price_per_pill {
+        let max_lamports_in = curve_pills_out
+            .checked_mul(max_price as u128)
+            .ok_or(CrazySolError::Overflow)?;
+
+        let scaled_lamports_in = (lamports_in as u128)
+            .checked_mul(FIXED_POINT_SCALE)
+            .ok_or(CrazySolError::Overflow)?;
+
+        require!(
+            curve_pills_out > 0 && scaled_lamports_in <= max_lamports_in,
+            CrazySolError::PricePerPillTooHigh
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::{prelude::*, solana_program::hash::hash};
+
+use crate::{errors::CrazySolError, normalize_handle, states::{ScientistState, UsernameRecord}};
+
+// Usernames can exceed the 32-byte seed limit, so records are keyed by the hash
+// of the name as `normalize_handle` sees it: trimmed and lowercased.
+pub fn username_seed(username: &str) -> [u8; 32] {
+    hash(username.trim().to_ascii_lowercase().as_bytes()).to_bytes()
+}
+
+pub fn normalize_username(username: &str) -> Result<String> {
+    require!(
+        username.len() <= ScientistState::MAX_USERNAME_LENGTH,
+        CrazySolError::UsernameTooLong
+    );
+    normalize_handle(
+        username,
+        UsernameRecord::MIN_USERNAME_LENGTH,
+        ScientistState::MAX_USERNAME_LENGTH
+    )
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CrazySolError, states::VestingSchedule};
+
+impl VestingSchedule {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.cliff_seconds >= 0 && self.duration_seconds >= 0 && self.tge_unlock_bps <= 10_000,
+            CrazySolError::InvalidVestingSchedule
+        );
+        Ok(())
+    }
+
+    // The TGE share unlocks at innoculation, the rest linearly over
+    // duration_seconds once the cliff has passed.
+    pub fn vested_amount(&self, total_amount: u64, start_ts: i64, current_ts: i64) -> Result<u64> {
+        let total_amount = total_amount as u128;
+        let tge_amount = total_amount
+            .checked_mul(self.tge_unlock_bps as u128)
+            .ok_or(CrazySolError::Overflow)?
+            / 10_000;
+
+        let elapsed = current_ts.saturating_sub(start_ts);
+        if elapsed < self.cliff_seconds {
+            return Ok(tge_amount as u64);
+        }
+
+        let vesting_elapsed = (elapsed - self.cliff_seconds) as u128;
+        let duration = self.duration_seconds as u128;
+        if vesting_elapsed >= duration {
+            return Ok(total_amount as u64);
+        }
+
+        let linear_amount = (total_amount - tge_amount)
+            .checked_mul(vesting_elapsed)
+            .ok_or(CrazySolError::Overflow)?
+            / duration;
+
+        Ok((tge_amount + linear_amount) as u64)
+    }
+}
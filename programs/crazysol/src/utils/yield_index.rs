@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::YIELD_INDEX_PRECISION, errors::CrazySolError, states::ReactorState, utils::{to_u128, U256}};
+
+impl ReactorState {
+    // Spreads an inflow over the current Pill supply. Inflows received while no
+    // Pill exists are held back and folded into the next distribution.
+    pub fn distribute_yield(&mut self, lamports: u64) -> Result<()> {
+        let distributable = self.undistributed_yield
+            .checked_add(lamports)
+            .ok_or(CrazySolError::Overflow)?;
+
+        if self.pill_supply == 0 {
+            self.undistributed_yield = distributable;
+            return Ok(());
+        }
+
+        let increment = to_u128(
+            U256::from(distributable) * U256::from(YIELD_INDEX_PRECISION) / U256::from(self.pill_supply)
+        )?;
+
+        self.acc_yield_per_pill = self.acc_yield_per_pill
+            .checked_add(increment)
+            .ok_or(CrazySolError::Overflow)?;
+        self.outstanding_yield = self.outstanding_yield
+            .checked_add(distributable)
+            .ok_or(CrazySolError::Overflow)?;
+        self.undistributed_yield = 0;
+
+        Ok(())
+    }
+
+    pub fn settle_yield(&mut self, lamports: u64) -> Result<()> {
+        self.outstanding_yield = self.outstanding_yield
+            .checked_sub(lamports)
+            .ok_or(CrazySolError::Overflow)?;
+        Ok(())
+    }
+}